@@ -2,7 +2,7 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{self};
 
-#[proc_macro_derive(CosmicConfigEntry, attributes(version, id))]
+#[proc_macro_derive(CosmicConfigEntry, attributes(version, id, fallback))]
 pub fn cosmic_config_entry_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
@@ -52,10 +52,28 @@ fn impl_cosmic_config_entry_macro(ast: &syn::DeriveInput) -> TokenStream {
     let get_each_config_field = fields.iter().map(|field| {
         let field_name = &field.ident;
         let field_type = &field.ty;
+        let Some(fallback) = field_fallback(field) else {
+            return quote! {
+                match cosmic_config::ConfigGet::get::<#field_type>(config, stringify!(#field_name)) {
+                    Ok(#field_name) => default.#field_name = #field_name,
+                    Err(why) if matches!(why, cosmic_config::Error::NoConfigDirectory) => (),
+                    Err(e) => errors.push(e),
+                }
+            };
+        };
+
+        // Keys missing from older configs are derived once every other field is loaded.
+        // A key missing from the user config without a system default is reported as
+        // having no config directory.
         quote! {
             match cosmic_config::ConfigGet::get::<#field_type>(config, stringify!(#field_name)) {
                 Ok(#field_name) => default.#field_name = #field_name,
-                Err(why) if matches!(why, cosmic_config::Error::NoConfigDirectory) => (),
+                Err(cosmic_config::Error::NoConfigDirectory) => fallbacks.push(#fallback),
+                Err(cosmic_config::Error::GetKey(_, why))
+                    if why.kind() == std::io::ErrorKind::NotFound =>
+                {
+                    fallbacks.push(#fallback);
+                }
                 Err(e) => errors.push(e),
             }
         }
@@ -115,9 +133,15 @@ fn impl_cosmic_config_entry_macro(ast: &syn::DeriveInput) -> TokenStream {
             fn get_entry(config: &cosmic_config::Config) -> Result<Self, (Vec<cosmic_config::Error>, Self)> {
                 let mut default = Self::default();
                 let mut errors = Vec::new();
+                #[allow(unused_mut)]
+                let mut fallbacks: Vec<fn(&mut Self)> = Vec::new();
 
                 #(#get_each_config_field)*
 
+                for fallback in fallbacks {
+                    fallback(&mut default);
+                }
+
                 if errors.is_empty() {
                     Ok(default)
                 } else {
@@ -145,3 +169,26 @@ fn impl_cosmic_config_entry_macro(ast: &syn::DeriveInput) -> TokenStream {
 
     gen.into()
 }
+
+/// The function named by a field's `#[fallback = "path"]` attribute, wrapped so
+/// that it assigns the derived value to the field.
+fn field_fallback(field: &syn::Field) -> Option<impl quote::ToTokens> {
+    let field_name = &field.ident;
+    let path = field.attrs.iter().find_map(|attr| {
+        if !attr.path.is_ident("fallback") {
+            return None;
+        }
+
+        match attr.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            })) => Some(lit_str.parse::<syn::ExprPath>().unwrap()),
+            _ => None,
+        }
+    })?;
+
+    Some(quote! {
+        |entry: &mut Self| entry.#field_name = #path(entry)
+    })
+}
@@ -21,7 +21,9 @@ pub const LIGHT_THEME_BUILDER_ID: &str = "com.system76.CosmicTheme.Light.Builder
 /// ID for the current light Theme config
 pub const LIGHT_THEME_ID: &str = "com.system76.CosmicTheme.Light";
 
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
 /// Theme layer type
 pub enum Layer {
     /// Background layer
@@ -31,6 +33,45 @@ pub enum Layer {
     Primary,
     /// Secondary Layer
     Secondary,
+    /// Tertiary Layer
+    Tertiary,
+}
+
+impl Layer {
+    #[must_use]
+    /// The layer of a container nested within a container of this layer
+    ///
+    /// Saturates at [`Layer::Tertiary`], which is the deepest layer.
+    pub fn child_layer(self) -> Self {
+        promote_layer(self, true)
+    }
+}
+
+#[must_use]
+/// The layer of a child placed within a widget on the `current` layer
+///
+/// Children of a container are promoted one layer deeper, saturating at
+/// [`Layer::Tertiary`]. Children of anything else stay on `current`. The first
+/// container nested past [`Layer::Tertiary`] logs a warning.
+pub fn promote_layer(current: Layer, is_container: bool) -> Layer {
+    if !is_container {
+        return current;
+    }
+
+    match current {
+        Layer::Background => Layer::Primary,
+        Layer::Primary => Layer::Secondary,
+        Layer::Secondary => Layer::Tertiary,
+        Layer::Tertiary => {
+            static WARNED: std::sync::Once = std::sync::Once::new();
+
+            WARNED.call_once(|| {
+                tracing::warn!("containers nested past the tertiary layer reuse its colors");
+            });
+
+            Layer::Tertiary
+        }
+    }
 }
 
 #[must_use]
@@ -53,6 +94,9 @@ pub struct Theme {
     pub primary: Container,
     /// secondary element colors
     pub secondary: Container,
+    /// tertiary element colors
+    #[fallback = "Theme::tertiary_fallback"]
+    pub tertiary: Container,
     /// accent element colors
     pub accent: Component,
     /// suggested element colors
@@ -153,6 +197,17 @@ impl Theme {
         todo!();
     }
 
+    #[must_use]
+    /// get the container colors of a layer
+    pub fn layer(&self, layer: Layer) -> &Container {
+        match layer {
+            Layer::Background => &self.background,
+            Layer::Primary => &self.primary,
+            Layer::Secondary => &self.secondary,
+            Layer::Tertiary => &self.tertiary,
+        }
+    }
+
     // TODO convenient getter functions for each named color variable
     #[must_use]
     #[allow(clippy::doc_markdown)]
@@ -228,6 +283,18 @@ impl Theme {
     }
     #[must_use]
     #[allow(clippy::doc_markdown)]
+    /// get @tertiary_container_color
+    pub fn tertiary_container_color(&self) -> Srgba {
+        self.tertiary.base
+    }
+    #[must_use]
+    #[allow(clippy::doc_markdown)]
+    /// get @tertiary_component_color
+    pub fn tertiary_component_color(&self) -> Srgba {
+        self.tertiary.component.base
+    }
+    #[must_use]
+    #[allow(clippy::doc_markdown)]
     /// get @button_bg_color
     pub fn button_bg_color(&self) -> Srgba {
         self.button.base
@@ -272,6 +339,18 @@ impl Theme {
     }
     #[must_use]
     #[allow(clippy::doc_markdown)]
+    /// get @on_tertiary_color
+    pub fn on_tertiary_container_color(&self) -> Srgba {
+        self.tertiary.on
+    }
+    #[must_use]
+    #[allow(clippy::doc_markdown)]
+    /// get @on_tertiary_component_color
+    pub fn on_tertiary_component_color(&self) -> Srgba {
+        self.tertiary.component.on
+    }
+    #[must_use]
+    #[allow(clippy::doc_markdown)]
     /// get @accent_text_color
     pub fn accent_text_color(&self) -> Srgba {
         self.accent.base
@@ -358,6 +437,12 @@ impl Theme {
     }
     #[must_use]
     #[allow(clippy::doc_markdown)]
+    /// get @tertiary_container_divider
+    pub fn tertiary_container_divider(&self) -> Srgba {
+        self.tertiary.divider
+    }
+    #[must_use]
+    #[allow(clippy::doc_markdown)]
     /// get @button_divider
     pub fn button_divider(&self) -> Srgba {
         self.button.divider
@@ -489,6 +574,27 @@ impl Theme {
         Self::get_entry(&config)
     }
 
+    #[must_use]
+    /// Derive the tertiary container for a theme whose config predates it
+    ///
+    /// The tertiary container steps from the secondary container as it does
+    /// when the theme is built, so that it follows the stored customizations.
+    pub fn tertiary_fallback(&self) -> Container {
+        let step_array = steps(self.background.base, NonZeroUsize::new(100).unwrap());
+        let neutral_steps = steps(Rgb::new(0.0, 0.0, 0.0), NonZeroUsize::new(100).unwrap());
+
+        tertiary_container(
+            self.secondary.base,
+            &step_array,
+            &neutral_steps,
+            None,
+            &self.palette,
+            self.accent_color(),
+            self.is_dark,
+            self.is_high_contrast,
+        )
+    }
+
     #[must_use]
     /// Rebuild the current theme with the provided accent
     pub fn with_accent(&self, c: Srgba) -> Self {
@@ -845,6 +951,10 @@ impl ThemeBuilder {
             text_steps_array.as_ref(),
         );
 
+        let secondary_container_bg = secondary_container_bg.unwrap_or_else(|| {
+            get_surface_color(bg_index, 10, &step_array, is_dark, &p_ref.neutral_2)
+        });
+
         let mut theme: Theme = Theme {
             name: palette.name().to_string(),
             shade: if palette.is_dark() {
@@ -932,11 +1042,7 @@ impl ThemeBuilder {
                 container
             },
             secondary: {
-                let container_bg = if let Some(secondary_container_bg) = secondary_container_bg {
-                    secondary_container_bg
-                } else {
-                    get_surface_color(bg_index, 10, &step_array, is_dark, &p_ref.neutral_2)
-                };
+                let container_bg = secondary_container_bg;
 
                 let base_index = color_index(container_bg, step_array.len());
                 let secondary_component =
@@ -983,6 +1089,16 @@ impl ThemeBuilder {
                     ),
                 )
             },
+            tertiary: tertiary_container(
+                secondary_container_bg,
+                &step_array,
+                &neutral_steps,
+                text_steps_array.as_ref(),
+                p_ref,
+                accent,
+                is_dark,
+                is_high_contrast,
+            ),
             accent: Component::colored_component(
                 accent,
                 p_ref.neutral_0,
@@ -1112,3 +1228,143 @@ impl ThemeBuilder {
         Config::new(LIGHT_THEME_BUILDER_ID, Self::VERSION)
     }
 }
+
+/// The tertiary container, one step deeper than a secondary container of `secondary_bg`
+#[allow(clippy::too_many_arguments)]
+fn tertiary_container(
+    secondary_bg: Srgba,
+    step_array: &Vec<Srgba>,
+    neutral_steps: &Vec<Srgba>,
+    text_steps_array: Option<&Vec<Srgba>>,
+    p_ref: &CosmicPaletteInner,
+    accent: Srgba,
+    is_dark: bool,
+    is_high_contrast: bool,
+) -> Container {
+    // Step from the secondary container so an overridden secondary is respected
+    let container_bg = get_surface_color(
+        color_index(secondary_bg, step_array.len()),
+        5,
+        step_array,
+        is_dark,
+        &p_ref.neutral_3,
+    );
+
+    let base_index = color_index(container_bg, step_array.len());
+    let tertiary_component =
+        get_surface_color(base_index, 3, step_array, is_dark, &p_ref.neutral_5);
+
+    let mut component_hovered_overlay = if base_index < 91 {
+        p_ref.neutral_10
+    } else {
+        p_ref.neutral_0
+    };
+    component_hovered_overlay.alpha = 0.1;
+
+    let mut component_pressed_overlay = component_hovered_overlay;
+    component_pressed_overlay.alpha = 0.2;
+
+    Container::new(
+        Component::component(
+            tertiary_component,
+            accent,
+            get_text(
+                color_index(tertiary_component, step_array.len()),
+                step_array,
+                &p_ref.neutral_8,
+                text_steps_array,
+            ),
+            component_hovered_overlay,
+            component_pressed_overlay,
+            is_high_contrast,
+            p_ref.neutral_8,
+        ),
+        container_bg,
+        get_text(base_index, step_array, &p_ref.neutral_8, text_steps_array),
+        get_surface_color(
+            base_index,
+            5,
+            neutral_steps,
+            base_index <= 65,
+            &p_ref.neutral_6,
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{promote_layer, Layer, Theme, ThemeBuilder};
+    use cosmic_config::{Config, CosmicConfigEntry};
+
+    #[test]
+    fn test_promote_layer() {
+        assert_eq!(promote_layer(Layer::Background, true), Layer::Primary);
+        assert_eq!(promote_layer(Layer::Primary, true), Layer::Secondary);
+        assert_eq!(promote_layer(Layer::Secondary, true), Layer::Tertiary);
+        assert_eq!(promote_layer(Layer::Tertiary, true), Layer::Tertiary);
+    }
+
+    #[test]
+    fn test_promote_layer_non_container() {
+        for layer in [
+            Layer::Background,
+            Layer::Primary,
+            Layer::Secondary,
+            Layer::Tertiary,
+        ] {
+            assert_eq!(promote_layer(layer, false), layer);
+            assert_eq!(layer.child_layer(), promote_layer(layer, true));
+        }
+    }
+
+    #[test]
+    fn test_tertiary_fallback_matches_build() {
+        for builder in [
+            ThemeBuilder::dark(),
+            ThemeBuilder::light(),
+            ThemeBuilder::dark_high_contrast(),
+            ThemeBuilder::light_high_contrast(),
+        ] {
+            let theme = builder.build();
+            assert_eq!(theme.tertiary_fallback(), theme.tertiary);
+        }
+    }
+
+    #[test]
+    fn test_tertiary_fallback_follows_secondary() {
+        let mut secondary_bg = ThemeBuilder::light().build().secondary.base;
+        secondary_bg.red *= 0.5;
+
+        let theme = ThemeBuilder {
+            secondary_container_bg: Some(secondary_bg),
+            ..ThemeBuilder::light()
+        }
+        .build();
+        assert_eq!(theme.tertiary_fallback(), theme.tertiary);
+    }
+
+    #[test]
+    fn test_migrate_config_without_tertiary() {
+        let path = std::env::temp_dir().join(format!("cosmic-theme-test-{}", std::process::id()));
+        let config = Config::with_custom_path(
+            "com.system76.CosmicTheme.Test",
+            Theme::VERSION,
+            path.clone(),
+        )
+        .unwrap();
+
+        let theme = ThemeBuilder::light().build();
+        theme.write_entry(&config).unwrap();
+
+        // Configs written before the tertiary layer existed lack its key.
+        let tertiary = path.join("cosmic/com.system76.CosmicTheme.Test/v1/tertiary");
+        std::fs::remove_file(tertiary).unwrap();
+
+        let loaded = Theme::get_entry(&config);
+        std::fs::remove_dir_all(&path).unwrap();
+
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.tertiary, theme.tertiary);
+        assert_eq!(loaded, theme);
+    }
+}
@@ -32,7 +32,10 @@ pub struct Window {
     pub header_title: String,
     pub use_template: bool,
     pub content_container: bool,
+    pub maximized: bool,
     pub sharp_corners: bool,
+    /// Edges of the window which are tiled.
+    pub tiled: crate::widget::TiledEdges,
     pub show_context: bool,
     pub show_headerbar: bool,
     pub show_window_menu: bool,
@@ -138,7 +141,9 @@ impl Default for Core {
                 header_title: String::new(),
                 use_template: true,
                 content_container: true,
+                maximized: false,
                 sharp_corners: false,
+                tiled: crate::widget::TiledEdges::default(),
                 show_context: false,
                 show_headerbar: true,
                 show_maximize: true,
@@ -315,6 +315,7 @@ impl<T: Application> Cosmic<T> {
         match message {
             Message::WindowMaximized(id, maximized) => {
                 if self.app.main_window_id() == id {
+                    self.app.core_mut().window.maximized = maximized;
                     self.app.core_mut().window.sharp_corners = maximized;
                 }
             }
@@ -337,6 +338,8 @@ impl<T: Application> Cosmic<T> {
             #[cfg(feature = "wayland")]
             Message::WindowState(id, state) => {
                 if self.app.main_window_id() == id {
+                    self.app.core_mut().window.maximized =
+                        state.intersects(WindowState::MAXIMIZED | WindowState::FULLSCREEN);
                     self.app.core_mut().window.sharp_corners = state.intersects(
                         WindowState::MAXIMIZED
                             | WindowState::FULLSCREEN
@@ -346,6 +349,15 @@ impl<T: Application> Cosmic<T> {
                             | WindowState::TILED_TOP
                             | WindowState::TILED_BOTTOM,
                     );
+
+                    // Compositors without per-edge tiling report every edge as tiled.
+                    let tiled = state.contains(WindowState::TILED);
+                    self.app.core_mut().window.tiled = crate::widget::TiledEdges {
+                        left: tiled || state.contains(WindowState::TILED_LEFT),
+                        right: tiled || state.contains(WindowState::TILED_RIGHT),
+                        top: tiled || state.contains(WindowState::TILED_TOP),
+                        bottom: tiled || state.contains(WindowState::TILED_BOTTOM),
+                    };
                 }
             }
 
@@ -691,6 +691,8 @@ impl<App: Application> ApplicationExt for App {
                 Some({
                     let mut header = crate::widget::header_bar()
                         .focused(focused)
+                        .maximized(core.window.maximized)
+                        .tiled(core.window.tiled)
                         .title(&core.window.header_title)
                         .on_drag(Message::Cosmic(cosmic::Message::Drag))
                         .on_close(Message::Cosmic(cosmic::Message::Close))
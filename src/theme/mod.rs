@@ -187,6 +187,13 @@ impl ThemeType {
     }
 }
 
+/// Whether a theme draws dark content on a light background, or light content on a dark one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
 #[must_use]
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Theme {
@@ -253,17 +260,49 @@ impl Theme {
     /// get current container
     /// can be used in a component that is intended to be a child of a `CosmicContainer`
     pub fn current_container(&self) -> &cosmic_theme::Container {
-        match self.layer {
-            cosmic_theme::Layer::Background => &self.cosmic().background,
-            cosmic_theme::Layer::Primary => &self.cosmic().primary,
-            cosmic_theme::Layer::Secondary => &self.cosmic().secondary,
-        }
+        self.layer_container(self.layer)
+    }
+
+    /// get the container of a layer
+    pub fn layer_container(&self, layer: cosmic_theme::Layer) -> &cosmic_theme::Container {
+        self.cosmic().layer(layer)
     }
 
     /// set the theme
     pub fn set_theme(&mut self, theme: ThemeType) {
         self.theme_type = theme;
     }
+
+    /// The color scheme of the theme.
+    pub fn color_scheme(&self) -> ColorScheme {
+        if self.cosmic().is_dark {
+            ColorScheme::Dark
+        } else {
+            ColorScheme::Light
+        }
+    }
+
+    /// A copy of the theme with the given color scheme.
+    ///
+    /// The theme is returned as is if it already has the scheme. Otherwise, the default COSMIC
+    /// theme of that scheme is used, keeping the layer and high contrast preference.
+    pub fn with_color_scheme(&self, scheme: ColorScheme) -> Self {
+        if self.color_scheme() == scheme {
+            return self.clone();
+        }
+
+        let theme = match (scheme, self.theme_type.is_high_contrast()) {
+            (ColorScheme::Dark, false) => Self::dark(),
+            (ColorScheme::Dark, true) => Self::dark_hc(),
+            (ColorScheme::Light, false) => Self::light(),
+            (ColorScheme::Light, true) => Self::light_hc(),
+        };
+
+        Self {
+            layer: self.layer,
+            ..theme
+        }
+    }
 }
 
 impl LayeredTheme for Theme {
@@ -271,3 +310,40 @@ impl LayeredTheme for Theme {
         self.layer = layer;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ColorScheme, Theme};
+
+    #[test]
+    fn test_with_color_scheme() {
+        let theme = Theme {
+            layer: cosmic_theme::Layer::Primary,
+            ..Theme::dark()
+        };
+
+        let light = theme.with_color_scheme(ColorScheme::Light);
+        assert_eq!(light.color_scheme(), ColorScheme::Light);
+        assert_eq!(light.theme_type, Theme::light().theme_type);
+        assert_eq!(light.layer, cosmic_theme::Layer::Primary);
+    }
+
+    #[test]
+    fn test_with_color_scheme_keeps_high_contrast() {
+        let light = Theme::dark_hc().with_color_scheme(ColorScheme::Light);
+        assert_eq!(light.theme_type, Theme::light_hc().theme_type);
+
+        let dark = Theme::light_hc().with_color_scheme(ColorScheme::Dark);
+        assert_eq!(dark.theme_type, Theme::dark_hc().theme_type);
+    }
+
+    #[test]
+    fn test_with_same_color_scheme() {
+        let theme = Theme {
+            layer: cosmic_theme::Layer::Secondary,
+            ..Theme::light()
+        };
+
+        assert_eq!(theme.with_color_scheme(ColorScheme::Light), theme);
+    }
+}
@@ -379,6 +379,7 @@ pub enum Container {
     List,
     Primary,
     Secondary,
+    Tertiary,
     Tooltip,
     #[default]
     Transparent,
@@ -430,6 +431,20 @@ impl Container {
             shadow: Shadow::default(),
         }
     }
+
+    #[must_use]
+    pub fn tertiary(theme: &cosmic_theme::Theme) -> container::Appearance {
+        container::Appearance {
+            icon_color: Some(Color::from(theme.tertiary.on)),
+            text_color: Some(Color::from(theme.tertiary.on)),
+            background: Some(iced::Background::Color(theme.tertiary.base.into())),
+            border: Border {
+                radius: theme.corner_radii.radius_xs.into(),
+                ..Default::default()
+            },
+            shadow: Shadow::default(),
+        }
+    }
 }
 
 impl container::StyleSheet for Theme {
@@ -530,6 +545,8 @@ impl container::StyleSheet for Theme {
 
             Container::Secondary => Container::secondary(cosmic),
 
+            Container::Tertiary => Container::tertiary(cosmic),
+
             Container::Dropdown => {
                 let theme = self.cosmic();
 
@@ -559,7 +576,7 @@ impl container::StyleSheet for Theme {
             Container::Card => {
                 let cosmic = self.cosmic();
 
-                match self.layer {
+                let mut appearance = match self.layer {
                     cosmic_theme::Layer::Background => container::Appearance {
                         icon_color: Some(Color::from(cosmic.background.component.on)),
                         text_color: Some(Color::from(cosmic.background.component.on)),
@@ -596,7 +613,27 @@ impl container::StyleSheet for Theme {
                         },
                         shadow: Shadow::default(),
                     },
+                    cosmic_theme::Layer::Tertiary => container::Appearance {
+                        icon_color: Some(Color::from(cosmic.tertiary.component.on)),
+                        text_color: Some(Color::from(cosmic.tertiary.component.on)),
+                        background: Some(iced::Background::Color(
+                            cosmic.tertiary.component.base.into(),
+                        )),
+                        border: Border {
+                            radius: cosmic.corner_radii.radius_s.into(),
+                            ..Default::default()
+                        },
+                        shadow: Shadow::default(),
+                    },
+                };
+
+                // Outline cards in high contrast themes, rather than relying on a subtle fill.
+                if cosmic.is_high_contrast {
+                    appearance.border.width = 1.0;
+                    appearance.border.color = self.current_container().component.border.into();
                 }
+
+                appearance
             }
 
             Container::Dialog => container::Appearance {
@@ -1262,6 +1299,10 @@ impl crate::widget::card::style::StyleSheet for Theme {
                 card_1: Background::Color(cosmic.secondary.component.hover.into()),
                 card_2: Background::Color(cosmic.secondary.component.pressed.into()),
             },
+            cosmic_theme::Layer::Tertiary => crate::widget::card::style::Appearance {
+                card_1: Background::Color(cosmic.tertiary.component.hover.into()),
+                card_2: Background::Color(cosmic.tertiary.component.pressed.into()),
+            },
         }
     }
 }
@@ -7,6 +7,42 @@ use crate::ext::ColorExt;
 use crate::widget::text_input::{Appearance, StyleSheet};
 use iced_core::Color;
 
+/// The weight of the foreground in placeholder text blended with the surface color.
+const PLACEHOLDER_ALPHA: f32 = 0.7;
+
+/// Minimum contrast ratio between placeholder text and its surface.
+const PLACEHOLDER_MIN_CONTRAST: f32 = 4.5;
+
+/// A muted foreground color for placeholder text on the given container.
+///
+/// The foreground is blended toward the surface beneath the translucent field background, and
+/// made less muted on surfaces where that would leave it with too little contrast.
+fn placeholder_color(container: &cosmic_theme::Container) -> Color {
+    let on: Color = container.on.into();
+    let surface: Color = container.base.into();
+
+    let mut alpha = PLACEHOLDER_ALPHA;
+    let mut color = on.blend_alpha(surface, alpha);
+
+    while alpha < 1.0 && contrast_ratio(color, surface) < PLACEHOLDER_MIN_CONTRAST {
+        alpha = (alpha + 0.05).min(1.0);
+        color = on.blend_alpha(surface, alpha);
+    }
+
+    color
+}
+
+/// The WCAG contrast ratio of two opaque colors.
+fn contrast_ratio(a: Color, b: Color) -> f32 {
+    fn luminance(color: Color) -> f32 {
+        let [r, g, b, _] = color.into_linear();
+        0.0722f32.mul_add(b, 0.2126f32.mul_add(r, 0.7152 * g))
+    }
+
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
 #[derive(Default)]
 pub enum TextInput {
     #[default]
@@ -45,10 +81,7 @@ impl StyleSheet for crate::Theme {
                 border_color: container.component.divider.into(),
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -61,10 +94,7 @@ impl StyleSheet for crate::Theme {
                 border_color: Color::TRANSPARENT,
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -77,10 +107,7 @@ impl StyleSheet for crate::Theme {
                 border_color: Color::TRANSPARENT,
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -93,10 +120,7 @@ impl StyleSheet for crate::Theme {
                 border_color: container.component.divider.into(),
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -109,10 +133,7 @@ impl StyleSheet for crate::Theme {
                 border_color: Color::TRANSPARENT,
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -140,10 +161,7 @@ impl StyleSheet for crate::Theme {
                 border_color: Color::from(palette.destructive_color()),
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -156,10 +174,7 @@ impl StyleSheet for crate::Theme {
                 border_color: Color::TRANSPARENT,
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -172,10 +187,7 @@ impl StyleSheet for crate::Theme {
                 border_color: Color::TRANSPARENT,
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -203,10 +215,7 @@ impl StyleSheet for crate::Theme {
                 border_color: palette.accent.base.into(),
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -219,10 +228,7 @@ impl StyleSheet for crate::Theme {
                 border_color: palette.accent.base.into(),
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -235,10 +241,7 @@ impl StyleSheet for crate::Theme {
                 border_color: Color::TRANSPARENT,
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -251,10 +254,7 @@ impl StyleSheet for crate::Theme {
                 border_color: Color::TRANSPARENT,
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -267,10 +267,7 @@ impl StyleSheet for crate::Theme {
                 border_color: Color::TRANSPARENT,
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -298,10 +295,7 @@ impl StyleSheet for crate::Theme {
                 border_color: palette.accent.base.into(),
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -314,10 +308,7 @@ impl StyleSheet for crate::Theme {
                 border_color: palette.accent.base.into(),
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -332,10 +323,7 @@ impl StyleSheet for crate::Theme {
                 // in this case, for selected and unselected text
                 icon_color: None,
                 text_color: None,
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -350,10 +338,7 @@ impl StyleSheet for crate::Theme {
                 // TODO use regular text color here after text rendering handles multiple colors
                 // in this case, for selected and unselected text
                 text_color: Some(palette.on_accent_color().into()),
-                placeholder_color: {
-                    let color: Color = container.on.into();
-                    color.blend_alpha(background, 0.7)
-                },
+                placeholder_color: placeholder_color(container),
                 selected_text_color: palette.on_accent_color().into(),
                 selected_fill: palette.accent_color().into(),
                 label_color: label_color.into(),
@@ -370,3 +355,51 @@ impl StyleSheet for crate::Theme {
         self.active(style)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_core::Color;
+
+    use super::{contrast_ratio, placeholder_color, PLACEHOLDER_MIN_CONTRAST};
+
+    #[test]
+    fn test_contrast_ratio() {
+        let ratio = contrast_ratio(Color::BLACK, Color::WHITE);
+        assert!((ratio - 21.0).abs() < 0.01);
+
+        // The ratio does not depend on the order of the colors.
+        assert_eq!(ratio, contrast_ratio(Color::WHITE, Color::BLACK));
+        assert_eq!(contrast_ratio(Color::WHITE, Color::WHITE), 1.0);
+    }
+
+    #[test]
+    fn test_placeholder_color_contrast() {
+        for theme in [
+            cosmic_theme::Theme::dark_default(),
+            cosmic_theme::Theme::light_default(),
+        ] {
+            for container in [&theme.background, &theme.primary, &theme.secondary] {
+                let surface: Color = container.base.into();
+                let placeholder = placeholder_color(container);
+
+                assert!(contrast_ratio(placeholder, surface) >= PLACEHOLDER_MIN_CONTRAST);
+                // The placeholder stays muted relative to the foreground.
+                assert!(
+                    contrast_ratio(placeholder, surface)
+                        <= contrast_ratio(container.on.into(), surface)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_placeholder_color_low_contrast_surface() {
+        let mut theme = cosmic_theme::Theme::dark_default();
+        let container = &mut theme.background;
+        container.base = palette::Srgba::new(0.4, 0.4, 0.4, 1.0);
+        container.on = palette::Srgba::new(0.0, 0.0, 0.0, 1.0);
+
+        // The foreground itself falls short, so the placeholder is left unmuted.
+        assert_eq!(placeholder_color(container), Color::BLACK);
+    }
+}
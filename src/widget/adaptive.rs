@@ -0,0 +1,393 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A container which arranges its children in a row when wide, and a column when narrow.
+
+use crate::{Element, Renderer};
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::layout::flex::{self, Axis};
+use iced_core::widget::{tree, Operation, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, Alignment, Clipboard, Layout, Length, Padding, Rectangle,
+    Shell, Size, Widget,
+};
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+/// A container which arranges its children in a row when wide, and a column when narrow.
+///
+/// The children are laid out in a row whenever the available width reaches the `breakpoint`.
+pub fn adaptive<'a, Message>(breakpoint: f32) -> Adaptive<'a, Message> {
+    Adaptive::new(breakpoint)
+}
+
+/// The orientation of a device or window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+impl Orientation {
+    /// How far the aspect ratio must move past square before the orientation changes.
+    ///
+    /// Prevents the orientation from flickering for nearly square sizes.
+    pub const HYSTERESIS: f32 = 0.1;
+
+    /// The orientation of `size`, given the orientation last chosen.
+    #[must_use]
+    pub fn from_size(size: Size, previous: Option<Self>) -> Self {
+        let ratio = size.width / size.height.max(1.0);
+
+        match previous {
+            Some(Self::Landscape) if ratio >= 1.0 - Self::HYSTERESIS => Self::Landscape,
+            Some(Self::Portrait) if ratio <= 1.0 + Self::HYSTERESIS => Self::Portrait,
+            _ if ratio >= 1.0 => Self::Landscape,
+            _ => Self::Portrait,
+        }
+    }
+}
+
+/// A container which arranges its children in a row when wide, and a column when narrow.
+#[must_use]
+#[derive(Setters)]
+pub struct Adaptive<'a, Message> {
+    #[setters(skip)]
+    children: Vec<Element<'a, Message>>,
+    /// The available width at which the children switch from a column to a row.
+    breakpoint: f32,
+    /// How far past the breakpoint the width must move before switching back.
+    ///
+    /// Prevents the orientation from flickering while resizing around the breakpoint.
+    hysteresis: f32,
+    /// Arranges the children in a row for landscape, and a column for portrait, regardless of
+    /// the breakpoint.
+    #[setters(strip_option)]
+    orientation: Option<Orientation>,
+    /// Sets the padding around the widget.
+    padding: Padding,
+    /// Sets the space between each child.
+    spacing: u16,
+    /// Alignment of the children along the cross axis.
+    align_items: Alignment,
+    /// Sets the width of the container.
+    width: Length,
+    /// Sets the height of the container.
+    height: Length,
+}
+
+impl<'a, Message> Adaptive<'a, Message> {
+    pub const fn new(breakpoint: f32) -> Self {
+        Self {
+            children: Vec::new(),
+            breakpoint,
+            hysteresis: 8.0,
+            orientation: None,
+            padding: Padding::ZERO,
+            spacing: 0,
+            align_items: Alignment::Start,
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Attach a new element to the container.
+    pub fn push(mut self, widget: impl Into<Element<'a, Message>>) -> Self {
+        self.children.push(widget.into());
+        self
+    }
+
+    /// Decides the orientation from the device orientation if set, or else the available width
+    /// and the orientation last chosen.
+    fn is_horizontal(&self, previous: Option<bool>, available_width: f32) -> bool {
+        if let Some(orientation) = self.orientation {
+            return orientation == Orientation::Landscape;
+        }
+
+        match previous {
+            Some(true) => available_width >= self.breakpoint - self.hysteresis,
+            Some(false) => available_width >= self.breakpoint + self.hysteresis,
+            None => available_width >= self.breakpoint,
+        }
+    }
+}
+
+impl<'a, Message: 'static + Clone> Widget<Message, crate::Theme, Renderer>
+    for Adaptive<'a, Message>
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(self.children.as_mut_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let horizontal = self.is_horizontal(state.horizontal, limits.max().width);
+        state.horizontal = Some(horizontal);
+
+        flex::resolve(
+            if horizontal {
+                Axis::Horizontal
+            } else {
+                Axis::Vertical
+            },
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            self.padding,
+            f32::from(self.spacing),
+            self.align_items,
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| c.as_widget().a11y_nodes(c_layout, state, p)),
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_style::core::clipboard::DndDestinationRectangles,
+    ) {
+        for ((e, layout), state) in self
+            .children
+            .iter()
+            .zip(layout.children())
+            .zip(state.children.iter())
+        {
+            e.as_widget()
+                .drag_destinations(state, layout, dnd_rectangles);
+        }
+    }
+}
+
+impl<'a, Message: 'static + Clone> From<Adaptive<'a, Message>> for Element<'a, Message> {
+    fn from(adaptive: Adaptive<'a, Message>) -> Self {
+        Self::new(adaptive)
+    }
+}
+
+/// The orientation chosen by the last layout, if any.
+#[derive(Default)]
+struct State {
+    horizontal: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Adaptive, Orientation};
+    use iced_core::Size;
+
+    #[test]
+    fn test_breakpoint() {
+        let adaptive = Adaptive::<()>::new(600.0);
+
+        assert!(adaptive.is_horizontal(None, 600.0));
+        assert!(!adaptive.is_horizontal(None, 599.0));
+    }
+
+    #[test]
+    fn test_breakpoint_hysteresis() {
+        let adaptive = Adaptive::<()>::new(600.0).hysteresis(8.0);
+
+        // A row stays a row until the width drops past the hysteresis below the breakpoint.
+        assert!(adaptive.is_horizontal(Some(true), 593.0));
+        assert!(adaptive.is_horizontal(Some(true), 592.0));
+        assert!(!adaptive.is_horizontal(Some(true), 591.0));
+
+        // A column stays a column until the width rises past the hysteresis above it.
+        assert!(!adaptive.is_horizontal(Some(false), 607.0));
+        assert!(adaptive.is_horizontal(Some(false), 608.0));
+    }
+
+    #[test]
+    fn test_orientation_from_size() {
+        assert_eq!(
+            Orientation::from_size(Size::new(800.0, 600.0), None),
+            Orientation::Landscape
+        );
+        assert_eq!(
+            Orientation::from_size(Size::new(600.0, 800.0), None),
+            Orientation::Portrait
+        );
+        assert_eq!(
+            Orientation::from_size(Size::new(600.0, 600.0), None),
+            Orientation::Landscape
+        );
+    }
+
+    #[test]
+    fn test_orientation_hysteresis() {
+        // Nearly square sizes keep the orientation last chosen.
+        let narrow = Size::new(570.0, 600.0);
+        let wide = Size::new(630.0, 600.0);
+
+        assert_eq!(
+            Orientation::from_size(narrow, Some(Orientation::Landscape)),
+            Orientation::Landscape
+        );
+        assert_eq!(
+            Orientation::from_size(wide, Some(Orientation::Portrait)),
+            Orientation::Portrait
+        );
+
+        // Moving past the hysteresis switches it.
+        assert_eq!(
+            Orientation::from_size(Size::new(500.0, 600.0), Some(Orientation::Landscape)),
+            Orientation::Portrait
+        );
+        assert_eq!(
+            Orientation::from_size(Size::new(700.0, 600.0), Some(Orientation::Portrait)),
+            Orientation::Landscape
+        );
+    }
+
+    #[test]
+    fn test_orientation_overrides_breakpoint() {
+        let portrait = Adaptive::<()>::new(600.0).orientation(Orientation::Portrait);
+        assert!(!portrait.is_horizontal(Some(true), 1000.0));
+
+        let landscape = Adaptive::<()>::new(600.0).orientation(Orientation::Landscape);
+        assert!(landscape.is_horizontal(Some(false), 100.0));
+    }
+}
@@ -0,0 +1,372 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Animate the size of a widget toward the size of its content.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, window, Clipboard, Layout, Length, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Animates its size toward the size of its content whenever the content changes size.
+pub fn animated_size<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+) -> AnimatedSize<'a, Message> {
+    AnimatedSize::new(content)
+}
+
+/// Animates its size toward the size of its content whenever the content changes size.
+///
+/// Useful for popups whose content grows or shrinks, which would otherwise jump to the new
+/// size. The content is laid out at its new size immediately, and clipped to the animated size
+/// while growing, with the cursor hidden from the parts clipped away. A change of size during an
+/// animation starts a new one from the current size.
+#[derive(Setters)]
+#[must_use]
+pub struct AnimatedSize<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    /// Time taken to reach the new size.
+    duration: Duration,
+}
+
+impl<'a, Message> AnimatedSize<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            content: content.into(),
+            duration: Duration::from_millis(150),
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for AnimatedSize<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        let target = node.size();
+        let state = tree.state.downcast_mut::<State>();
+
+        let size = state
+            .animation
+            .get_or_insert_with(|| Animation::new(target))
+            .retarget(target);
+
+        layout::Node::with_children(
+            limits.resolve(Length::Shrink, Length::Shrink, size),
+            vec![node],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if let Some(animation) = state
+                .animation
+                .as_mut()
+                .filter(|animation| animation.current != animation.target)
+            {
+                if animation.advance(now, self.duration) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                shell.invalidate_layout();
+            }
+        }
+
+        let cursor = visible_cursor(cursor, layout);
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            visible_cursor(cursor, layout),
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        use iced_core::Renderer as _;
+
+        let bounds = layout.bounds();
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout.children().next().unwrap(),
+                cursor,
+                &clip,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content.as_widget().drag_destinations(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            dnd_rectangles,
+        );
+    }
+}
+
+impl<'a, Message: 'a> From<AnimatedSize<'a, Message>> for Element<'a, Message> {
+    fn from(animated_size: AnimatedSize<'a, Message>) -> Self {
+        Self::new(animated_size)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    animation: Option<Animation>,
+}
+
+/// The cursor, if it is within the animated bounds rather than over clipped content.
+fn visible_cursor(cursor: mouse::Cursor, layout: Layout<'_>) -> mouse::Cursor {
+    if cursor.is_over(layout.bounds()) {
+        cursor
+    } else {
+        mouse::Cursor::Unavailable
+    }
+}
+
+struct Animation {
+    from: Size,
+    target: Size,
+    current: Size,
+    /// The frame the animation toward `target` started on.
+    started: Option<Instant>,
+}
+
+impl Animation {
+    fn new(size: Size) -> Self {
+        Self {
+            from: size,
+            target: size,
+            current: size,
+            started: None,
+        }
+    }
+
+    /// Starts animating from the current size if the target changed, returning the current size.
+    fn retarget(&mut self, target: Size) -> Size {
+        if self.target != target {
+            self.from = self.current;
+            self.target = target;
+            self.started = None;
+        }
+
+        self.current
+    }
+
+    /// Advances the animation to the frame drawn at `now`, returning whether it continues.
+    fn advance(&mut self, now: Instant, duration: Duration) -> bool {
+        let started = *self.started.get_or_insert(now);
+        let progress = if duration.is_zero() {
+            1.0
+        } else {
+            (now.saturating_duration_since(started).as_secs_f32() / duration.as_secs_f32()).min(1.0)
+        };
+
+        if progress >= 1.0 {
+            self.current = self.target;
+            return false;
+        }
+
+        // Ease out, so that the animation settles gently on the new size.
+        let eased = 1.0 - (1.0 - progress).powi(3);
+
+        self.current = Size::new(
+            (self.target.width - self.from.width).mul_add(eased, self.from.width),
+            (self.target.height - self.from.height).mul_add(eased, self.from.height),
+        );
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::time::{Duration, Instant};
+    use iced_core::Size;
+
+    use super::Animation;
+
+    const DURATION: Duration = Duration::from_millis(100);
+
+    /// The sizes of the frames drawn every 25ms until the animation settles.
+    fn frames(animation: &mut Animation) -> Vec<Size> {
+        let start = Instant::now();
+        let mut sizes = Vec::new();
+
+        for frame in 0.. {
+            let more = animation.advance(start + Duration::from_millis(25 * frame), DURATION);
+            sizes.push(animation.current);
+
+            if !more {
+                break;
+            }
+        }
+
+        sizes
+    }
+
+    #[test]
+    fn test_grow_over_frames() {
+        let mut animation = Animation::new(Size::new(100.0, 50.0));
+        assert_eq!(
+            animation.retarget(Size::new(200.0, 150.0)),
+            Size::new(100.0, 50.0)
+        );
+
+        let sizes = frames(&mut animation);
+
+        assert_eq!(sizes.len(), 5);
+        assert_eq!(sizes[0], Size::new(100.0, 50.0));
+        assert!(sizes
+            .windows(2)
+            .all(|pair| pair[0].width < pair[1].width && pair[0].height < pair[1].height));
+        assert_eq!(sizes[4], Size::new(200.0, 150.0));
+    }
+
+    #[test]
+    fn test_shrink_over_frames() {
+        let mut animation = Animation::new(Size::new(200.0, 150.0));
+        animation.retarget(Size::new(100.0, 50.0));
+
+        let sizes = frames(&mut animation);
+
+        assert_eq!(sizes.len(), 5);
+        assert!(sizes
+            .windows(2)
+            .all(|pair| pair[0].width > pair[1].width && pair[0].height > pair[1].height));
+        assert_eq!(sizes[4], Size::new(100.0, 50.0));
+    }
+
+    #[test]
+    fn test_retarget_mid_animation() {
+        let start = Instant::now();
+        let mut animation = Animation::new(Size::new(100.0, 100.0));
+        animation.retarget(Size::new(200.0, 100.0));
+
+        animation.advance(start, DURATION);
+        animation.advance(start + Duration::from_millis(50), DURATION);
+        let current = animation.current;
+
+        // A new target starts from where the animation is, not from where it began.
+        assert_eq!(animation.retarget(Size::new(100.0, 100.0)), current);
+        assert_eq!(animation.from, current);
+    }
+}
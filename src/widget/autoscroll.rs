@@ -0,0 +1,345 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Scroll a scrollable automatically while a drag hovers near its top or bottom edge.
+
+use derive_setters::Setters;
+use iced::clipboard::dnd::{DndEvent, OfferEvent};
+use iced_core::event::{self, Event};
+use iced_core::mouse::{self, ScrollDelta};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, overlay, renderer, window, Clipboard, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// The longest frame which is accounted for, so that a stalled frame does not jump the view.
+const MAX_FRAME_TIME: Duration = Duration::from_millis(100);
+
+/// Scrolls its scrollable content while a drag hovers near its top or bottom edge.
+pub fn autoscroll<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+) -> Autoscroll<'a, Message> {
+    Autoscroll::new(content)
+}
+
+/// Scrolls its scrollable content while a drag hovers near its top or bottom edge.
+///
+/// While a drag and drop offer is within the edge zone, the content is sent scroll events
+/// toward that edge on every frame, faster as the pointer gets closer to the edge. Scrolling
+/// stops when the pointer leaves the zone or the drag ends, and the scrollable itself stops at
+/// its extent.
+#[derive(Setters)]
+#[must_use]
+pub struct Autoscroll<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    /// Height of the zone along each edge which scrolls.
+    edge: f32,
+    /// Scroll speed, in pixels per second, when the pointer is at the very edge.
+    speed: f32,
+}
+
+impl<'a, Message> Autoscroll<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            content: content.into(),
+            edge: 48.0,
+            speed: 800.0,
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for Autoscroll<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let mut scroll = None;
+
+        match event {
+            Event::Dnd(DndEvent::Offer(
+                _,
+                OfferEvent::Enter { x, y, .. } | OfferEvent::Motion { x, y },
+            )) => {
+                #[allow(clippy::cast_possible_truncation)]
+                let position = Point::new(x as f32, y as f32);
+                state.pointer = Some(position);
+
+                if velocity(layout.bounds(), position, self.edge, self.speed) != 0.0 {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+
+            Event::Dnd(DndEvent::Offer(_, OfferEvent::Leave | OfferEvent::Drop)) => {
+                state.pointer = None;
+                state.last_frame = None;
+            }
+
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                if let Some(position) = state.pointer {
+                    let velocity = velocity(layout.bounds(), position, self.edge, self.speed);
+
+                    if velocity != 0.0 {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+
+                    scroll = state.frame(now, velocity).map(|delta| (position, delta));
+                }
+            }
+
+            _ => (),
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let Some((position, delta)) = scroll {
+            _ = self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                Event::Mouse(mouse::Event::WheelScrolled {
+                    delta: ScrollDelta::Pixels { x: 0.0, y: delta },
+                }),
+                layout,
+                mouse::Cursor::Available(position),
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<Autoscroll<'a, Message>> for Element<'a, Message> {
+    fn from(autoscroll: Autoscroll<'a, Message>) -> Self {
+        Self::new(autoscroll)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// Position of the drag over the surface, while one is in progress.
+    pointer: Option<Point>,
+    /// Time of the last frame that scrolled.
+    last_frame: Option<Instant>,
+}
+
+impl State {
+    /// Advances to the frame at `now`, returning how far to scroll since the last frame.
+    ///
+    /// The first frame of a scroll only marks its start, so nothing is scrolled until the next.
+    fn frame(&mut self, now: Instant, velocity: f32) -> Option<f32> {
+        if velocity == 0.0 {
+            self.last_frame = None;
+            return None;
+        }
+
+        let delta = self.last_frame.map(|last| {
+            velocity
+                * now
+                    .saturating_duration_since(last)
+                    .min(MAX_FRAME_TIME)
+                    .as_secs_f32()
+        });
+
+        self.last_frame = Some(now);
+        delta
+    }
+}
+
+/// Scroll velocity for a pointer at `position`, with positive values scrolling up.
+///
+/// The speed grows from zero at the inner side of the `edge` zone to `speed` at the very edge.
+fn velocity(bounds: Rectangle, position: Point, edge: f32, speed: f32) -> f32 {
+    if !bounds.contains(position) || edge <= 0.0 {
+        return 0.0;
+    }
+
+    let top = position.y - bounds.y;
+    let bottom = bounds.y + bounds.height - position.y;
+
+    if top < edge && top <= bottom {
+        speed * (1.0 - top / edge)
+    } else if bottom < edge {
+        -speed * (1.0 - bottom / edge)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{velocity, State};
+    use iced_core::time::{Duration, Instant};
+    use iced_core::{Point, Rectangle, Size};
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 200.0,
+        height: 400.0,
+    };
+
+    /// Scroll deltas over frames 50ms apart, for an offer at `y`.
+    fn scroll(y: f32, frames: u32) -> Vec<Option<f32>> {
+        let mut state = State::default();
+        let start = Instant::now();
+        let velocity = velocity(BOUNDS, Point::new(100.0, y), 48.0, 800.0);
+
+        (0..frames)
+            .map(|frame| state.frame(start + Duration::from_millis(50) * frame, velocity))
+            .collect()
+    }
+
+    #[test]
+    fn test_offer_near_edge_scrolls() {
+        // A quarter of the way into the zone, scrolling at three quarters of the speed.
+        assert_eq!(scroll(12.0, 3), [None, Some(30.0), Some(30.0)]);
+        assert_eq!(scroll(388.0, 3), [None, Some(-30.0), Some(-30.0)]);
+    }
+
+    #[test]
+    fn test_offer_away_from_edge_does_not_scroll() {
+        assert_eq!(scroll(200.0, 3), [None, None, None]);
+
+        let outside = Rectangle::new(Point::new(0.0, 400.0), Size::new(200.0, 10.0));
+        assert_eq!(velocity(BOUNDS, outside.center(), 48.0, 800.0), 0.0);
+    }
+
+    #[test]
+    fn test_stalled_frame_is_clamped() {
+        let mut state = State::default();
+        let start = Instant::now();
+
+        assert_eq!(state.frame(start, 600.0), None);
+        assert_eq!(
+            state.frame(start + Duration::from_secs(1), 600.0),
+            Some(60.0)
+        );
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Lay out static content once, and reuse that layout on later frames.
+
+use std::hash::{Hash, Hasher};
+
+use iced_core::event::{self, Event};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, Clipboard, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Lays out its content once, and reuses that layout until its limits or key change.
+pub fn cached_layout<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+) -> CachedLayout<'a, Message> {
+    CachedLayout::new(content)
+}
+
+/// Lays out its content once, and reuses that layout until its limits or key change.
+///
+/// Intended for content which is expensive to measure and never changes, such as a long block
+/// of text. The content is laid out again when the minimum or maximum size available to it
+/// changes, or when the [`key`](Self::key) differs from the one it was last laid out with.
+/// Content which changes without a change of key will be drawn with a stale layout.
+#[must_use]
+pub struct CachedLayout<'a, Message> {
+    content: Element<'a, Message>,
+    key: u64,
+}
+
+impl<'a, Message> CachedLayout<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            content: content.into(),
+            key: 0,
+        }
+    }
+
+    /// Lays out the content again whenever this value changes.
+    pub fn key(mut self, key: impl Hash) -> Self {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        key.hash(&mut hasher);
+        self.key = hasher.finish();
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for CachedLayout<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let previous = tree.children[0].tag;
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+
+        // The content was replaced with a different widget, whose state has not been laid out.
+        if tree.children[0].tag != previous {
+            tree.state.downcast_mut::<State>().cache = None;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        tree.state
+            .downcast_mut::<State>()
+            .node(self.key, limits, || {
+                self.content
+                    .as_widget()
+                    .layout(&mut tree.children[0], renderer, limits)
+            })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<CachedLayout<'a, Message>> for Element<'a, Message> {
+    fn from(cached_layout: CachedLayout<'a, Message>) -> Self {
+        Self::new(cached_layout)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    cache: Option<Cache>,
+}
+
+impl State {
+    /// The cached node if it was laid out with `key` and `limits`, or else a new one from
+    /// `layout`, which is cached in its place.
+    fn node(
+        &mut self,
+        key: u64,
+        limits: &layout::Limits,
+        layout: impl FnOnce() -> layout::Node,
+    ) -> layout::Node {
+        let (min, max) = (limits.min(), limits.max());
+
+        if let Some(cache) = self
+            .cache
+            .as_ref()
+            .filter(|cache| cache.key == key && cache.min == min && cache.max == max)
+        {
+            return cache.node.clone();
+        }
+
+        let node = layout();
+
+        self.cache = Some(Cache {
+            key,
+            min,
+            max,
+            node: node.clone(),
+        });
+
+        node
+    }
+}
+
+/// The layout of the content, and the conditions it was computed under.
+struct Cache {
+    key: u64,
+    min: Size,
+    max: Size,
+    node: layout::Node,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+    use iced_core::{layout, Size};
+    use std::cell::Cell;
+
+    #[test]
+    fn test_cache_invalidated_by_limits() {
+        let mut state = State::default();
+        let layouts = Cell::new(0);
+        let mut node = |key, limits: &layout::Limits| {
+            state.node(key, limits, || {
+                layouts.set(layouts.get() + 1);
+                layout::Node::new(limits.max())
+            })
+        };
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0));
+        node(0, &limits);
+        node(0, &limits);
+        assert_eq!(layouts.get(), 1);
+
+        // A change of any limit lays out again, not only of the maximum width.
+        node(0, &limits.min_width(50.0));
+        assert_eq!(layouts.get(), 2);
+
+        node(0, &limits.max_height(80.0));
+        assert_eq!(layouts.get(), 3);
+
+        node(0, &limits.max_height(80.0));
+        assert_eq!(layouts.get(), 3);
+
+        // As does a change of key.
+        node(1, &limits.max_height(80.0));
+        assert_eq!(layouts.get(), 4);
+    }
+}
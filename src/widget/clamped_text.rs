@@ -0,0 +1,240 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Text which wraps onto at most a fixed number of lines, ending in an ellipsis if truncated.
+
+use crate::{Element, Renderer};
+use derive_setters::Setters;
+use iced_core::text::{LineHeight, Paragraph, Renderer as TextRenderer, Shaping, Text};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    alignment, layout, mouse, renderer, Font, Layout, Length, Pixels, Rectangle, Size, Widget,
+};
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Text which wraps onto at most `max_lines` lines, ending in an ellipsis if truncated.
+pub fn clamped_text<'a>(content: impl Into<Cow<'a, str>>, max_lines: usize) -> ClampedText<'a> {
+    ClampedText::new(content, max_lines)
+}
+
+/// Text which wraps onto at most a fixed number of lines, ending in an ellipsis if truncated.
+///
+/// The height of the widget is that of the lines actually shown, so content shorter than the
+/// limit takes no more space than it needs. Nothing is shown when the limit is zero.
+#[derive(Setters)]
+#[must_use]
+pub struct ClampedText<'a> {
+    #[setters(skip)]
+    content: Cow<'a, str>,
+    /// The maximum number of lines shown.
+    max_lines: usize,
+    /// Sets the width of the widget.
+    #[setters(into)]
+    width: Length,
+    /// The font size of the text. Defaults to the renderer's default size.
+    #[setters(strip_option)]
+    size: Option<f32>,
+    /// Sets the line height, relative to the font size.
+    #[setters(into)]
+    line_height: LineHeight,
+    /// The font used to shape and draw the text.
+    font: Font,
+}
+
+impl<'a> ClampedText<'a> {
+    pub fn new(content: impl Into<Cow<'a, str>>, max_lines: usize) -> Self {
+        Self {
+            content: content.into(),
+            max_lines,
+            width: Length::Shrink,
+            size: None,
+            line_height: LineHeight::default(),
+            font: crate::font::DEFAULT,
+        }
+    }
+
+    fn paragraph(&self, content: &str, size: f32, max_width: f32) -> crate::Paragraph {
+        crate::Paragraph::with_text(Text {
+            content,
+            bounds: Size::new(max_width, f32::INFINITY),
+            size: Pixels(size),
+            line_height: self.line_height,
+            font: self.font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+        })
+    }
+
+    /// Shapes the content, truncating it to the longest prefix which fits within the lines.
+    #[allow(clippy::cast_precision_loss)]
+    fn clamp(&self, size: f32, max_width: f32) -> crate::Paragraph {
+        if self.max_lines == 0 {
+            return crate::Paragraph::new();
+        }
+
+        let max_height = self.line_height.to_absolute(Pixels(size)).0 * self.max_lines as f32 + 0.5;
+
+        let paragraph = self.paragraph(&self.content, size, max_width);
+
+        if paragraph.min_bounds().height <= max_height {
+            return paragraph;
+        }
+
+        let truncated = truncate(&self.content, |truncated| {
+            self.paragraph(truncated, size, max_width)
+                .min_bounds()
+                .height
+                <= max_height
+        });
+
+        self.paragraph(&truncated, size, max_width)
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for ClampedText<'a> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+        let size = self.size.unwrap_or_else(|| renderer.default_size().0);
+
+        let state = tree.state.downcast_mut::<State>();
+        let key = (max_width, size, self.max_lines, self.line_height, self.font);
+
+        if state.key != Some(key) || state.content != self.content {
+            state.paragraph = self.clamp(size, max_width);
+            state.content.clear();
+            state.content.push_str(&self.content);
+            state.key = Some(key);
+        }
+
+        let size = limits.resolve(self.width, Length::Shrink, state.paragraph.min_bounds());
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        renderer.fill_paragraph(
+            &state.paragraph,
+            layout.bounds().position(),
+            style.text_color,
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Message: 'a> From<ClampedText<'a>> for Element<'a, Message> {
+    fn from(clamped_text: ClampedText<'a>) -> Self {
+        Self::new(clamped_text)
+    }
+}
+
+/// The longest prefix of `content` which `fits` once an ellipsis is appended to it.
+///
+/// The content is cut between grapheme clusters, so that emoji and combining marks stay whole.
+fn truncate(content: &str, fits: impl Fn(&str) -> bool) -> String {
+    let boundaries: Vec<usize> = content.grapheme_indices(true).map(|(i, _)| i).collect();
+    let truncated = |n: usize| {
+        let end = boundaries.get(n).copied().unwrap_or(content.len());
+        format!("{}…", content[..end].trim_end())
+    };
+
+    // The longest prefix, in graphemes, whose truncation fits.
+    let (mut fit, mut overflow) = (0, boundaries.len());
+
+    while overflow - fit > 1 {
+        let n = (fit + overflow) / 2;
+
+        if fits(&truncated(n)) {
+            fit = n;
+        } else {
+            overflow = n;
+        }
+    }
+
+    truncated(fit)
+}
+
+struct State {
+    /// The content the paragraph was shaped for.
+    content: String,
+    /// The width, font size, line limit, line height, and font the paragraph was shaped for.
+    key: Option<(f32, f32, usize, LineHeight, Font)>,
+    paragraph: crate::Paragraph,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            content: String::new(),
+            key: None,
+            paragraph: crate::Paragraph::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::truncate;
+
+    /// Whether `text` fits on `lines` lines of `columns` characters each.
+    fn fits(text: &str, columns: usize, lines: usize) -> bool {
+        text.chars().count() <= columns * lines
+    }
+
+    #[test]
+    fn test_truncate_to_line_clamp() {
+        let content = "abcdefghijklmnopqrstuvwxyz";
+
+        assert_eq!(truncate(content, |text| fits(text, 5, 2)), "abcdefghi…");
+        assert_eq!(truncate(content, |text| fits(text, 5, 1)), "abcd…");
+    }
+
+    #[test]
+    fn test_truncate_trims_trailing_whitespace() {
+        assert_eq!(truncate("abcd efgh ijkl", |text| fits(text, 6, 1)), "abcd…");
+    }
+
+    #[test]
+    fn test_truncate_on_graphemes() {
+        // The family emoji is a single grapheme of seven chars, which is never split.
+        let content = "abcd\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}efgh";
+        assert_eq!(truncate(content, |text| fits(text, 6, 1)), "abcd…");
+
+        // A combining accent stays with the letter it modifies.
+        let content = "cafe\u{301} au lait";
+        assert_eq!(truncate(content, |text| fits(text, 5, 1)), "caf…");
+    }
+
+    #[test]
+    fn test_truncate_empty() {
+        assert_eq!(truncate("", |_| false), "…");
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Draw a widget with a color scheme which differs from the rest of the application.
+
+use iced_core::event::{self, Event};
+use iced_core::widget::{Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, Clipboard, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+use crate::theme::ColorScheme;
+use crate::{Element, Renderer};
+
+/// Draws its content with the given color scheme, whatever the scheme of the application.
+pub fn color_scheme<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    scheme: ColorScheme,
+) -> ForcedScheme<'a, Message> {
+    ForcedScheme::new(content, scheme)
+}
+
+/// Draws its content with the given color scheme, whatever the scheme of the application.
+///
+/// Descendants are drawn with [`crate::Theme::with_color_scheme`], so a widget can choose
+/// assets suited to its surroundings from [`crate::Theme::color_scheme`]. Nesting wrappers
+/// resolves to the innermost scheme. Overlays opened by the content are drawn with the theme
+/// of the application.
+#[must_use]
+pub struct ForcedScheme<'a, Message> {
+    content: Element<'a, Message>,
+    scheme: ColorScheme,
+}
+
+impl<'a, Message> ForcedScheme<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>, scheme: ColorScheme) -> Self {
+        Self {
+            content: content.into(),
+            scheme,
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for ForcedScheme<'a, Message> {
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if theme.color_scheme() == self.scheme {
+            return self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        let theme = theme.with_color_scheme(self.scheme);
+        let style = renderer::Style {
+            text_color: theme.current_container().on.into(),
+            ..*style
+        };
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            &theme,
+            &style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<ForcedScheme<'a, Message>> for Element<'a, Message> {
+    fn from(forced: ForcedScheme<'a, Message>) -> Self {
+        Self::new(forced)
+    }
+}
@@ -1,4 +1,6 @@
 use crate::widget::svg;
+use iced_core::widget::{operation, Id, Operation};
+use iced_core::Rectangle;
 use std::sync::OnceLock;
 
 /// Static `svg::Handle` to the `object-select-symbolic` icon.
@@ -16,3 +18,24 @@ pub fn object_select() -> &'static svg::Handle {
             })
     })
 }
+
+/// Finds whether any focusable widget has focus.
+#[derive(Default)]
+pub(crate) struct HasFocus(pub(crate) bool);
+
+impl<T> Operation<T> for HasFocus {
+    fn focusable(&mut self, state: &mut dyn operation::Focusable, _id: Option<&Id>) {
+        self.0 |= state.is_focused();
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if !self.0 {
+            operate_on_children(self);
+        }
+    }
+}
@@ -3,6 +3,7 @@
 
 //! A context menu is a menu in a graphical user interface that appears upon user interaction, such as a right-click mouse operation.
 
+use crate::widget::common::HasFocus;
 use crate::widget::menu::{
     self, CloseCondition, ItemHeight, ItemWidth, MenuBarState, PathHighlight,
 };
@@ -10,7 +11,7 @@ use derive_setters::Setters;
 use iced::touch::Finger;
 use iced::Event;
 use iced_core::widget::{tree, Tree, Widget};
-use iced_core::{event, mouse, touch, Length, Point, Size};
+use iced_core::{event, keyboard, mouse, touch, Length, Point, Size};
 use std::collections::HashSet;
 
 /// A context menu is a menu in a graphical user interface that appears upon user interaction, such as a right-click mouse operation.
@@ -164,9 +165,32 @@ impl<'a, Message: Clone> Widget<Message, crate::Theme, crate::Renderer>
         shell: &mut iced_core::Shell<'_, Message>,
         viewport: &iced::Rectangle,
     ) -> iced_core::event::Status {
-        let state = tree.state.downcast_mut::<LocalState>();
         let bounds = layout.bounds();
 
+        // Present a context menu beneath the content on the Menu key while it has focus.
+        if self.context_menu.is_some() && menu_key_pressed(&event) {
+            let mut operation = HasFocus::default();
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut operation,
+            );
+
+            if operation.0 {
+                let state = tree.state.downcast_mut::<LocalState>();
+                state.context_cursor = Point::new(bounds.x, bounds.y + bounds.height);
+
+                let menu_state = tree.children[1].state.downcast_mut::<MenuBarState>();
+                menu_state.open = true;
+                menu_state.view_cursor = mouse::Cursor::Available(state.context_cursor);
+
+                return event::Status::Captured;
+            }
+        }
+
+        let state = tree.state.downcast_mut::<LocalState>();
+
         if cursor.is_over(bounds) {
             let fingers_pressed = state.fingers_pressed.len();
 
@@ -270,6 +294,20 @@ fn touch_lifted(event: &Event) -> bool {
     matches!(event, Event::Touch(touch::Event::FingerLifted { .. }))
 }
 
+/// Whether the Menu key, or its Shift+F10 alternative, was pressed.
+fn menu_key_pressed(event: &Event) -> bool {
+    use keyboard::key::Named;
+
+    match event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modifiers,
+            ..
+        }) => *named == Named::ContextMenu || (*named == Named::F10 && modifiers.shift()),
+        _ => false,
+    }
+}
+
 pub struct LocalState {
     context_cursor: Point,
     fingers_pressed: HashSet<Finger>,
@@ -11,7 +11,7 @@ use crate::{
         },
         event,
         id::Internal,
-        mouse, overlay, Event, Length, Rectangle,
+        keyboard, mouse, overlay, touch, Event, Length, Rectangle,
     },
     iced_core::{
         self, layout,
@@ -70,6 +70,7 @@ pub struct DndDestination<'a, Message> {
     on_action_selected: Option<Box<dyn Fn(DndAction) -> Message>>,
     on_data_received: Option<Box<dyn Fn(String, Vec<u8>) -> Message>>,
     on_finish: Option<Box<dyn Fn(String, Vec<u8>, DndAction, f64, f64) -> Message>>,
+    on_paste: Option<Box<dyn Fn(String) -> Message>>,
 }
 
 impl<'a, Message: 'static> DndDestination<'a, Message> {
@@ -90,6 +91,7 @@ impl<'a, Message: 'static> DndDestination<'a, Message> {
             on_action_selected: None,
             on_data_received: None,
             on_finish: None,
+            on_paste: None,
         }
     }
 
@@ -115,6 +117,7 @@ impl<'a, Message: 'static> DndDestination<'a, Message> {
             on_finish: Some(Box::new(move |mime, data, action, _, _| {
                 on_finish(T::try_from((data, mime)).ok(), action)
             })),
+            on_paste: None,
         }
     }
 
@@ -150,6 +153,7 @@ impl<'a, Message: 'static> DndDestination<'a, Message> {
             on_action_selected: None,
             on_data_received: None,
             on_finish: None,
+            on_paste: None,
         }
     }
 
@@ -228,6 +232,16 @@ impl<'a, Message: 'static> DndDestination<'a, Message> {
         self
     }
 
+    /// Emits the text contents of the clipboard when pasted into the focused destination.
+    ///
+    /// The destination gains focus when pressed, and loses it when a press lands elsewhere.
+    /// Pastes are ignored when the clipboard is empty or holds no text.
+    #[must_use]
+    pub fn on_paste(mut self, f: impl Fn(String) -> Message + 'static) -> Self {
+        self.on_paste = Some(Box::new(f));
+        self
+    }
+
     /// Returns the drag id of the destination.
     ///
     /// # Panics
@@ -301,6 +315,13 @@ impl<'a, Message: 'static> Widget<Message, crate::Theme, crate::Renderer>
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let state = tree.state.downcast_mut::<State<()>>();
+            state.focused = cursor.is_over(layout.bounds());
+        }
+
         let s = self.container.as_widget_mut().on_event(
             &mut tree.children[0],
             event.clone(),
@@ -320,6 +341,23 @@ impl<'a, Message: 'static> Widget<Message, crate::Theme, crate::Renderer>
         let my_id = self.get_drag_id();
 
         match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if state.focused
+                && self.on_paste.is_some()
+                && c.eq_ignore_ascii_case("v")
+                && modifiers.command() =>
+            {
+                if let (Some(on_paste), Some(contents)) = (
+                    self.on_paste.as_ref(),
+                    clipboard.read().filter(|contents| !contents.is_empty()),
+                ) {
+                    shell.publish(on_paste(contents));
+                    return event::Status::Captured;
+                }
+            }
             Event::Dnd(DndEvent::Offer(
                 id,
                 OfferEvent::Enter {
@@ -541,6 +579,8 @@ impl<'a, Message: 'static> Widget<Message, crate::Theme, crate::Renderer>
 #[derive(Default)]
 pub struct State<T> {
     pub drag_offer: Option<DragOffer<T>>,
+    /// Whether the last press landed within the destination.
+    pub focused: bool,
 }
 
 pub struct DragOffer<T> {
@@ -554,7 +594,10 @@ pub struct DragOffer<T> {
 impl<T> State<T> {
     #[must_use]
     pub fn new() -> Self {
-        Self { drag_offer: None }
+        Self {
+            drag_offer: None,
+            focused: false,
+        }
     }
 
     pub fn on_enter<Message>(
@@ -31,6 +31,7 @@ pub struct DndSource<'a, Message, AppMessage, D> {
     drag_content: Option<Box<dyn Fn() -> D>>,
     drag_icon: Option<Box<dyn Fn() -> (Element<'static, AppMessage>, tree::State)>>,
     drag_threshold: f32,
+    drag_handle: Option<Box<dyn Fn(Rectangle) -> Rectangle>>,
     _phantom: std::marker::PhantomData<AppMessage>,
 }
 
@@ -49,6 +50,7 @@ impl<
             drag_content: None,
             drag_icon: None,
             drag_threshold: 8.0,
+            drag_handle: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -61,6 +63,7 @@ impl<
             drag_content: None,
             drag_icon: None,
             drag_threshold: 8.0,
+            drag_handle: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -92,6 +95,24 @@ impl<
         self
     }
 
+    /// Restricts drag initiation to a handle within the source.
+    ///
+    /// The closure receives the bounds of the source and returns the bounds of its handle. A
+    /// press inside the handle may start a drag even if a child captured it; presses elsewhere
+    /// are left to the children.
+    #[must_use]
+    pub fn drag_handle(mut self, f: impl Fn(Rectangle) -> Rectangle + 'static) -> Self {
+        self.drag_handle = Some(Box::new(f));
+        self
+    }
+
+    /// Whether a press at `position` may start a drag.
+    fn is_drag_start(&self, bounds: Rectangle, position: Point) -> bool {
+        self.drag_handle
+            .as_ref()
+            .map_or(true, |handle| handle(bounds).contains(position))
+    }
+
     pub fn start_dnd(&self, clipboard: &mut dyn Clipboard, bounds: Rectangle) {
         let Some(content) = self.drag_content.as_ref().map(|f| f()) else {
             return;
@@ -200,7 +221,7 @@ impl<
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if let Some(position) = cursor.position() {
-                        if !state.hovered {
+                        if !state.hovered || !self.is_drag_start(layout.bounds(), position) {
                             return ret;
                         }
 
@@ -0,0 +1,308 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Accept files dragged in from another application.
+
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+use iced::clipboard::dnd::{DndEvent, OfferEvent};
+use iced::clipboard::mime::AllowedMimeTypes;
+use iced_core::event::{self, Event};
+use iced_core::renderer::Quad;
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, window, Border, Clipboard, Color, Layout, Length, Rectangle,
+    Shadow, Shell, Size, Widget,
+};
+
+use crate::widget::DndDestination;
+use crate::{Element, Renderer};
+
+/// Opacity of the fill drawn while files are dragged over the widget.
+const HIGHLIGHT_ALPHA: f32 = 0.1;
+
+/// Emits the paths of files dropped onto its content.
+pub fn file_drop<'a, Message: 'static>(
+    content: impl Into<Element<'a, Message>>,
+    on_files_dropped: impl Fn(Vec<PathBuf>) -> Message + 'static,
+) -> FileDrop<'a, Message> {
+    FileDrop::new(content, on_files_dropped)
+}
+
+/// Local files offered as a `text/uri-list`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DroppedFiles(pub Vec<PathBuf>);
+
+impl AllowedMimeTypes for DroppedFiles {
+    fn allowed() -> Cow<'static, [String]> {
+        Cow::Owned(vec![String::from("text/uri-list")])
+    }
+}
+
+impl TryFrom<(Vec<u8>, String)> for DroppedFiles {
+    type Error = std::str::Utf8Error;
+
+    fn try_from((data, _mime): (Vec<u8>, String)) -> Result<Self, Self::Error> {
+        // Comments start with `#`, and URIs which are not local files are skipped.
+        let files = std::str::from_utf8(&data)?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| url::Url::parse(line).ok()?.to_file_path().ok())
+            .collect();
+
+        Ok(Self(files))
+    }
+}
+
+/// Emits the paths of files dropped onto its content.
+///
+/// While files are dragged over the widget, it is highlighted in the accent color; the
+/// highlight clears when the drag leaves or the files are dropped. Every file of a drop is
+/// emitted at once, and URIs which are not local files are ignored.
+#[must_use]
+pub struct FileDrop<'a, Message> {
+    content: Element<'a, Message>,
+    drag_id: u128,
+}
+
+impl<'a, Message: 'static> FileDrop<'a, Message> {
+    pub fn new(
+        content: impl Into<Element<'a, Message>>,
+        on_files_dropped: impl Fn(Vec<PathBuf>) -> Message + 'static,
+    ) -> Self {
+        let destination =
+            DndDestination::for_data(content, move |files: Option<DroppedFiles>, _action| {
+                on_files_dropped(files.unwrap_or_default().0)
+            });
+
+        Self {
+            drag_id: destination.get_drag_id(),
+            content: destination.into(),
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for FileDrop<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Dnd(DndEvent::Offer(Some(id), offer)) = &event {
+            if *id == self.drag_id {
+                let state = tree.state.downcast_mut::<State>();
+                let hovered = match offer {
+                    OfferEvent::Enter { .. } | OfferEvent::Motion { .. } => true,
+                    OfferEvent::Leave | OfferEvent::LeaveDestination | OfferEvent::Drop => false,
+                    _ => state.hovered,
+                };
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        use iced_core::Renderer as _;
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if !tree.state.downcast_ref::<State>().hovered {
+            return;
+        }
+
+        let cosmic = theme.cosmic();
+        let accent = Color::from(cosmic.accent_color());
+
+        renderer.fill_quad(
+            Quad {
+                bounds: layout.bounds(),
+                border: Border {
+                    color: accent,
+                    width: 2.0,
+                    radius: cosmic.corner_radii.radius_s.into(),
+                },
+                shadow: Shadow::default(),
+            },
+            Color {
+                a: HIGHLIGHT_ALPHA,
+                ..accent
+            },
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<FileDrop<'a, Message>> for Element<'a, Message> {
+    fn from(file_drop: FileDrop<'a, Message>) -> Self {
+        Self::new(file_drop)
+    }
+}
+
+/// Whether files are being dragged over the widget.
+#[derive(Default)]
+struct State {
+    hovered: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DroppedFiles;
+    use std::path::PathBuf;
+
+    fn parse(uri_list: &str) -> DroppedFiles {
+        DroppedFiles::try_from((uri_list.as_bytes().to_vec(), String::from("text/uri-list")))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_uri_list() {
+        let files = parse("file:///home/user/notes.txt\r\nfile:///home/user/My%20Photo.png\r\n");
+
+        assert_eq!(
+            files.0,
+            vec![
+                PathBuf::from("/home/user/notes.txt"),
+                PathBuf::from("/home/user/My Photo.png"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skip_comments_and_remote_uris() {
+        let files = parse(
+            "# dragged from a browser\r\n\
+             https://example.com/image.png\r\n\
+             \r\n\
+             file:///tmp/a.txt\r\n",
+        );
+
+        assert_eq!(files.0, vec![PathBuf::from("/tmp/a.txt")]);
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let data = vec![b'f', 0xff, 0xfe];
+        assert!(DroppedFiles::try_from((data, String::from("text/uri-list"))).is_err());
+    }
+}
@@ -0,0 +1,347 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Text which shrinks its font size to fit within its bounds.
+
+use crate::{Element, Renderer};
+use derive_setters::Setters;
+use iced_core::text::{LineHeight, Paragraph, Renderer as TextRenderer, Shaping, Text};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    alignment, layout, mouse, renderer, Font, Layout, Length, Pixels, Rectangle, Size, Widget,
+};
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Precision of the chosen font size, in pixels.
+const SIZE_STEP: f32 = 0.5;
+
+/// Text drawn at the largest font size, within a range, at which it fits its bounds.
+pub fn fit_text<'a>(content: impl Into<Cow<'a, str>>) -> FitText<'a> {
+    FitText::new(content)
+}
+
+/// Text drawn at the largest font size, within a range, at which it fits its bounds.
+///
+/// The bounds are set by the width and height of the widget, which fill the available space
+/// by default. Single line text shrinks until it fits the width; wrapped text shrinks until its
+/// lines fit the height. Text which does not fit even at the minimum size is clipped, or
+/// truncated with an ellipsis if [`ellipsize`](Self::ellipsize) is set.
+#[derive(Setters)]
+#[must_use]
+pub struct FitText<'a> {
+    #[setters(skip)]
+    content: Cow<'a, str>,
+    /// Sets the width of the widget.
+    #[setters(into)]
+    width: Length,
+    /// Sets the height of the widget.
+    #[setters(into)]
+    height: Length,
+    /// The smallest font size the text may shrink to.
+    min_size: f32,
+    /// The font size of the text when it fits.
+    max_size: f32,
+    /// Whether the text wraps onto multiple lines.
+    wrap: bool,
+    /// Whether text which does not fit at the minimum size ends in an ellipsis, instead of
+    /// being clipped.
+    ellipsize: bool,
+    /// Sets the line height, relative to the font size.
+    #[setters(into)]
+    line_height: LineHeight,
+    /// The font used to shape and draw the text.
+    font: Font,
+}
+
+impl<'a> FitText<'a> {
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            content: content.into(),
+            width: Length::Fill,
+            height: Length::Fill,
+            min_size: 8.0,
+            max_size: 14.0,
+            wrap: false,
+            ellipsize: false,
+            line_height: LineHeight::default(),
+            font: crate::font::DEFAULT,
+        }
+    }
+
+    fn paragraph(&self, content: &str, size: f32, bounds: Size) -> crate::Paragraph {
+        crate::Paragraph::with_text(Text {
+            content,
+            bounds: if self.wrap {
+                Size::new(bounds.width, f32::INFINITY)
+            } else {
+                Size::INFINITY
+            },
+            size: Pixels(size),
+            line_height: self.line_height,
+            font: self.font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+        })
+    }
+
+    fn fits(paragraph: &crate::Paragraph, bounds: Size) -> bool {
+        let size = paragraph.min_bounds();
+        size.width <= bounds.width + 0.5 && size.height <= bounds.height + 0.5
+    }
+
+    /// Shapes the text at the largest size which fits, or the minimum size if none does.
+    fn fit(&self, bounds: Size) -> crate::Paragraph {
+        let mut best = None;
+
+        let fitted = largest_fitting(self.min_size, self.max_size, |size| {
+            let paragraph = self.paragraph(&self.content, size, bounds);
+            let fits = Self::fits(&paragraph, bounds);
+
+            // Sizes which fit are only ever tried in increasing order.
+            if fits {
+                best = Some(paragraph);
+            }
+
+            fits
+        });
+
+        match (fitted, best) {
+            (Some(_), Some(paragraph)) => paragraph,
+            _ if self.ellipsize => self.truncate(self.min_size.min(self.max_size), bounds),
+            _ => self.paragraph(&self.content, self.min_size.min(self.max_size), bounds),
+        }
+    }
+
+    /// Shapes the longest prefix of the text which fits when followed by an ellipsis.
+    fn truncate(&self, size: f32, bounds: Size) -> crate::Paragraph {
+        let content = ellipsized(&self.content, |text| {
+            Self::fits(&self.paragraph(text, size, bounds), bounds)
+        });
+
+        self.paragraph(&content, size, bounds)
+    }
+}
+
+/// The longest prefix of `content`, followed by an ellipsis, for which `fits` holds.
+///
+/// Returns just the ellipsis if no prefix fits, and nothing if `content` is empty.
+fn ellipsized(content: &str, mut fits: impl FnMut(&str) -> bool) -> String {
+    // Cut between grapheme clusters, so that emoji and combining marks stay whole.
+    let boundaries: Vec<usize> = content.grapheme_indices(true).map(|(i, _)| i).collect();
+
+    if boundaries.is_empty() {
+        return String::new();
+    }
+
+    let truncated = |n: usize| format!("{}…", content[..boundaries[n]].trim_end());
+
+    // The longest prefix, in graphemes, whose truncation fits.
+    let (mut fitting, mut overflowing) = (0, boundaries.len());
+
+    while overflowing - fitting > 1 {
+        let n = (fitting + overflowing) / 2;
+
+        if fits(&truncated(n)) {
+            fitting = n;
+        } else {
+            overflowing = n;
+        }
+    }
+
+    truncated(fitting)
+}
+
+/// The largest size from `min` to `max` for which `fits` holds, to within [`SIZE_STEP`].
+///
+/// Returns `None` if nothing fits, not even at `min`.
+fn largest_fitting(min: f32, max: f32, mut fits: impl FnMut(f32) -> bool) -> Option<f32> {
+    let min = min.min(max);
+
+    if fits(max) {
+        return Some(max);
+    }
+
+    if !fits(min) {
+        return None;
+    }
+
+    let (mut fitting, mut overflowing) = (min, max);
+
+    while overflowing - fitting > SIZE_STEP {
+        let size = (fitting + overflowing) / 2.0;
+
+        if fits(size) {
+            fitting = size;
+        } else {
+            overflowing = size;
+        }
+    }
+
+    Some(fitting)
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for FitText<'a> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let bounds = limits.resolve(self.width, self.height, Size::ZERO);
+        let state = tree.state.downcast_mut::<State>();
+        let key = Key {
+            bounds,
+            min_size: self.min_size,
+            max_size: self.max_size,
+            wrap: self.wrap,
+            ellipsize: self.ellipsize,
+            line_height: self.line_height,
+            font: self.font,
+        };
+
+        if state.key != Some(key) || state.content != self.content {
+            state.paragraph = self.fit(bounds);
+            state.content.clear();
+            state.content.push_str(&self.content);
+            state.key = Some(key);
+        }
+
+        layout::Node::new(bounds)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+        renderer.fill_paragraph(&state.paragraph, bounds.position(), style.text_color, clip);
+    }
+}
+
+impl<'a, Message: 'a> From<FitText<'a>> for Element<'a, Message> {
+    fn from(fit_text: FitText<'a>) -> Self {
+        Self::new(fit_text)
+    }
+}
+
+struct State {
+    /// The content the paragraph was shaped for.
+    content: String,
+    key: Option<Key>,
+    paragraph: crate::Paragraph,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            content: String::new(),
+            key: None,
+            paragraph: crate::Paragraph::new(),
+        }
+    }
+}
+
+/// Everything besides the content which the paragraph was shaped for.
+#[derive(Clone, Copy, PartialEq)]
+struct Key {
+    bounds: Size,
+    min_size: f32,
+    max_size: f32,
+    wrap: bool,
+    ellipsize: bool,
+    line_height: LineHeight,
+    font: Font,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ellipsized, largest_fitting, SIZE_STEP};
+
+    /// Whether a label of `chars` characters, each 0.6em wide, fits within `width` at `size`.
+    fn label_fits(chars: f32, width: f32) -> impl Fn(f32) -> bool {
+        move |size| chars * 0.6 * size <= width
+    }
+
+    #[test]
+    fn test_largest_size_that_fits() {
+        // A 20 character label in a 100px box fits up to a size of 8.33.
+        let size = largest_fitting(6.0, 14.0, label_fits(20.0, 100.0)).unwrap();
+        let largest = 100.0 / (20.0 * 0.6);
+
+        assert!(size <= largest);
+        assert!(size > largest - SIZE_STEP);
+    }
+
+    #[test]
+    fn test_max_size_when_it_fits() {
+        assert_eq!(
+            largest_fitting(6.0, 14.0, label_fits(5.0, 100.0)),
+            Some(14.0)
+        );
+    }
+
+    #[test]
+    fn test_nothing_fits_at_min_size() {
+        assert_eq!(largest_fitting(6.0, 14.0, label_fits(100.0, 100.0)), None);
+    }
+
+    #[test]
+    fn test_min_size_larger_than_max_size() {
+        assert_eq!(largest_fitting(20.0, 14.0, |_| true), Some(14.0));
+    }
+
+    #[test]
+    fn test_ellipsize_longest_fitting_prefix() {
+        let fits = |text: &str| text.chars().count() <= 6;
+
+        assert_eq!(ellipsized("Hello world", fits), "Hello…");
+
+        // Whitespace before the ellipsis is dropped.
+        let fits = |text: &str| text.chars().count() <= 4;
+        assert_eq!(ellipsized("Hi there", fits), "Hi…");
+    }
+
+    #[test]
+    fn test_ellipsize_keeps_graphemes_whole() {
+        // Room for the thumb, but not its skin tone modifier.
+        let fits = |text: &str| text.len() <= "ab\u{1F44D}…".len();
+
+        assert_eq!(ellipsized("ab\u{1F44D}\u{1F3FD}cd", fits), "ab…");
+    }
+
+    #[test]
+    fn test_ellipsize_nothing_fits() {
+        assert_eq!(ellipsized("Hello", |_| false), "…");
+    }
+
+    #[test]
+    fn test_ellipsize_empty() {
+        assert_eq!(ellipsized("", |_| false), "");
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Highlight a container while any widget inside of it has focus.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::renderer::Quad;
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, window, Border, Clipboard, Color, Layout, Length, Rectangle,
+    Shadow, Shell, Size, Widget,
+};
+
+use crate::widget::common::HasFocus;
+use crate::{Element, Renderer};
+
+/// Outlines its content in the accent color while any widget inside of it has focus.
+pub fn focus_within<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+) -> FocusWithin<'a, Message> {
+    FocusWithin::new(content)
+}
+
+/// Outlines its content in the accent color while any widget inside of it has focus.
+///
+/// Focus is checked after each event is handled, so the outline stays in place while focus
+/// moves between widgets inside of the container.
+#[derive(Setters)]
+#[must_use]
+pub struct FocusWithin<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    #[setters(skip)]
+    on_change: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    /// Whether the outline is drawn.
+    highlight: bool,
+    /// Corner radius of the outline. Defaults to the small corner radius of the theme.
+    #[setters(strip_option)]
+    radius: Option<f32>,
+}
+
+impl<'a, Message> FocusWithin<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            content: content.into(),
+            on_change: None,
+            highlight: true,
+            radius: None,
+        }
+    }
+
+    /// Emits a message when focus enters or leaves the container.
+    pub fn on_change(mut self, on_change: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for FocusWithin<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Pointer motion never moves focus, and is too frequent to walk the tree for.
+        let check = !matches!(event, Event::Mouse(mouse::Event::CursorMoved { .. }));
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if check {
+            let mut operation = HasFocus::default();
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut operation,
+            );
+
+            let state = tree.state.downcast_mut::<State>();
+
+            if state.focused != operation.0 {
+                state.focused = operation.0;
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                if let Some(on_change) = self.on_change.as_ref() {
+                    shell.publish(on_change(operation.0));
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        use iced_core::Renderer as _;
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if !self.highlight || !tree.state.downcast_ref::<State>().focused {
+            return;
+        }
+
+        let cosmic = theme.cosmic();
+        let radius = self
+            .radius
+            .map_or_else(|| cosmic.corner_radii.radius_s.into(), Into::into);
+
+        renderer.fill_quad(
+            Quad {
+                bounds: layout.bounds(),
+                border: Border {
+                    width: 1.0,
+                    color: cosmic.accent_color().into(),
+                    radius,
+                },
+                shadow: Shadow::default(),
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<FocusWithin<'a, Message>> for Element<'a, Message> {
+    fn from(focus_within: FocusWithin<'a, Message>) -> Self {
+        Self::new(focus_within)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    focused: bool,
+}
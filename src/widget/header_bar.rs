@@ -3,9 +3,11 @@
 
 use crate::{ext::CollectionWidget, widget, Element};
 use apply::Apply;
+use cosmic_theme::CornerRadii;
 use derive_setters::Setters;
 use iced::Length;
 use iced_core::{widget::tree, Widget};
+use iced_style::container::StyleSheet;
 use std::borrow::Cow;
 
 #[must_use]
@@ -21,9 +23,21 @@ pub fn header_bar<'a, Message>() -> HeaderBar<'a, Message> {
         center: Vec::new(),
         end: Vec::new(),
         focused: false,
+        maximized: false,
+        tiled: TiledEdges::default(),
     }
 }
 
+/// Edges of a window which are tiled against the screen or other windows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct TiledEdges {
+    pub left: bool,
+    pub right: bool,
+    pub top: bool,
+    pub bottom: bool,
+}
+
 #[derive(Setters)]
 pub struct HeaderBar<'a, Message> {
     /// Defines the title of the window
@@ -53,6 +67,13 @@ pub struct HeaderBar<'a, Message> {
     /// Focused state of the window
     focused: bool,
 
+    /// Maximized state of the window, which shows a restore button in place of maximize and
+    /// squares the top corners.
+    maximized: bool,
+
+    /// Tiled edges of the window, where the top corners touching them are squared.
+    tiled: TiledEdges,
+
     /// Elements packed at the start of the headerbar.
     #[setters(skip)]
     start: Vec<Element<'a, Message>>,
@@ -299,9 +320,7 @@ impl<'a, Message: Clone + 'static> HeaderBar<'a, Message> {
             .padding(8)
             .spacing(8)
             .apply(widget::container)
-            .style(crate::theme::Container::HeaderBar {
-                focused: self.focused,
-            })
+            .style(header_bar_style(self.focused, self.maximized, self.tiled))
             .center_y()
             .apply(widget::mouse_area);
 
@@ -359,7 +378,11 @@ impl<'a, Message: Clone + 'static> HeaderBar<'a, Message> {
             }))
             .push_maybe(self.on_maximize.take().map(|m| {
                 icon(
-                    &include_bytes!("../../res/icons/window-maximize-symbolic.svg")[..],
+                    if self.maximized {
+                        &include_bytes!("../../res/icons/window-restore-symbolic.svg")[..]
+                    } else {
+                        &include_bytes!("../../res/icons/window-maximize-symbolic.svg")[..]
+                    },
                     16,
                     m,
                 )
@@ -379,6 +402,41 @@ impl<'a, Message: Clone + 'static> HeaderBar<'a, Message> {
     }
 }
 
+/// The header bar container style, with its top corners following those of the window.
+fn header_bar_style(focused: bool, maximized: bool, tiled: TiledEdges) -> crate::theme::Container {
+    if !maximized && tiled == TiledEdges::default() {
+        return crate::theme::Container::HeaderBar { focused };
+    }
+
+    crate::theme::Container::custom(move |theme| {
+        let mut appearance = theme.appearance(&crate::theme::Container::HeaderBar { focused });
+        appearance.border.radius =
+            corner_radii(&theme.cosmic().corner_radii, maximized, tiled).into();
+        appearance
+    })
+}
+
+/// Radii of the corners of the header bar.
+///
+/// The corners of a window are square when it fills the screen, and where either edge meeting
+/// at the corner is tiled.
+fn corner_radii(radii: &CornerRadii, maximized: bool, tiled: TiledEdges) -> [f32; 4] {
+    let corner = |index: usize, edge_tiled: bool| {
+        if maximized || tiled.top || edge_tiled {
+            radii.radius_0[index]
+        } else {
+            radii.radius_s[index]
+        }
+    };
+
+    [
+        corner(0, tiled.left),
+        corner(1, tiled.right),
+        radii.radius_0[2],
+        radii.radius_0[3],
+    ]
+}
+
 impl<'a, Message: Clone + 'static> From<HeaderBar<'a, Message>> for Element<'a, Message> {
     fn from(headerbar: HeaderBar<'a, Message>) -> Self {
         Element::new(headerbar.build())
@@ -390,3 +448,58 @@ impl<'a, Message: Clone + 'static> From<HeaderBarWidget<'a, Message>> for Elemen
         Element::new(headerbar)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{corner_radii, TiledEdges};
+    use cosmic_theme::CornerRadii;
+
+    #[test]
+    fn test_floating_window_rounds_top_corners() {
+        let radii = CornerRadii::default();
+
+        assert_eq!(
+            corner_radii(&radii, false, TiledEdges::default()),
+            [radii.radius_s[0], radii.radius_s[1], 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_maximized_window_squares_corners() {
+        let radii = CornerRadii::default();
+
+        assert_eq!(corner_radii(&radii, true, TiledEdges::default()), [0.0; 4]);
+    }
+
+    #[test]
+    fn test_tiled_window_squares_tiled_corners() {
+        let radii = CornerRadii::default();
+        let left = TiledEdges {
+            left: true,
+            top: false,
+            right: false,
+            bottom: true,
+        };
+        let right = TiledEdges {
+            left: false,
+            right: true,
+            ..left
+        };
+
+        assert_eq!(
+            corner_radii(&radii, false, left),
+            [0.0, radii.radius_s[1], 0.0, 0.0]
+        );
+        assert_eq!(
+            corner_radii(&radii, false, right),
+            [radii.radius_s[0], 0.0, 0.0, 0.0]
+        );
+
+        // Tiled along the top, both top corners touch a tiled edge.
+        let top = TiledEdges {
+            top: true,
+            ..TiledEdges::default()
+        };
+        assert_eq!(corner_radii(&radii, false, top), [0.0; 4]);
+    }
+}
@@ -0,0 +1,371 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Fade a hover style in and out as a widget is hovered.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::renderer::Quad;
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, window, Border, Clipboard, Color, Layout, Length, Rectangle,
+    Shell, Size, Widget,
+};
+use iced_style::container::Appearance;
+
+use crate::{Element, Renderer};
+
+/// The longest frame which is accounted for, so that the fade does not jump after a stall.
+const MAX_FRAME_TIME: Duration = Duration::from_millis(100);
+
+/// Fades a hover style in over its content while it is hovered.
+pub fn hover_fade<'a, Message>(content: impl Into<Element<'a, Message>>) -> HoverFade<'a, Message> {
+    HoverFade::new(content)
+}
+
+/// Fades a hover style in over its content while it is hovered.
+///
+/// The fade advances toward fully shown while the cursor is over the widget, and back toward
+/// hidden once it leaves, reversing smoothly if the cursor moves in and out mid-fade. Its
+/// progress, from 0 to 1, is given to the [`style`](Self::style) function, whose background is
+/// drawn behind the content and whose text and icon colors are passed down to the content.
+/// By default, the hover color of the current layer fades in behind the content.
+///
+/// Frames are only requested while a fade is in progress, and the fade is paused while the
+/// widget is scrolled out of view.
+#[derive(Setters)]
+#[must_use]
+pub struct HoverFade<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    /// Time taken to fade in or out completely. Set to zero to switch instantly.
+    duration: Duration,
+    #[setters(skip)]
+    style: Box<dyn Fn(&crate::Theme, f32) -> Appearance + 'a>,
+}
+
+impl<'a, Message> HoverFade<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            content: content.into(),
+            duration: Duration::from_millis(150),
+            style: Box::new(hover_background),
+        }
+    }
+
+    /// Sets the appearance for a given progress of the fade, from 0 to 1.
+    pub fn style(mut self, style: impl Fn(&crate::Theme, f32) -> Appearance + 'a) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+}
+
+/// The hover color of the current layer, faded in by `progress`.
+#[must_use]
+pub fn hover_background(theme: &crate::Theme, progress: f32) -> Appearance {
+    let mut color = Color::from(theme.current_container().component.hover);
+    color.a *= progress;
+
+    Appearance {
+        background: (progress > 0.0).then(|| color.into()),
+        border: Border {
+            radius: theme.cosmic().corner_radii.radius_s.into(),
+            ..Border::default()
+        },
+        ..Appearance::default()
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for HoverFade<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let hovered = cursor.is_over(layout.bounds());
+
+        match event {
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                let in_view = layout.bounds().intersects(viewport);
+
+                if state.frame(now, self.duration, in_view) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+
+            _ if hovered != state.hovered => {
+                state.hovered = hovered;
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            _ => (),
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        use iced_core::Renderer as _;
+
+        let progress = tree.state.downcast_ref::<State>().progress;
+        let appearance = (self.style)(theme, progress);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                Quad {
+                    bounds: layout.bounds(),
+                    border: appearance.border,
+                    shadow: appearance.shadow,
+                },
+                background,
+            );
+        }
+
+        let style = renderer::Style {
+            icon_color: appearance.icon_color.unwrap_or(style.icon_color),
+            text_color: appearance.text_color.unwrap_or(style.text_color),
+            scale_factor: style.scale_factor,
+        };
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<HoverFade<'a, Message>> for Element<'a, Message> {
+    fn from(hover_fade: HoverFade<'a, Message>) -> Self {
+        Self::new(hover_fade)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    hovered: bool,
+    /// How far the background has faded in, from 0 to 1.
+    progress: f32,
+    /// Time of the last frame of the fade in progress.
+    last_frame: Option<Instant>,
+}
+
+impl State {
+    /// Advances the fade to the frame at `now`.
+    ///
+    /// Returns whether the fade is still in progress. It is paused while out of view, and
+    /// resumes on the next frame drawn in view.
+    fn frame(&mut self, now: Instant, duration: Duration, in_view: bool) -> bool {
+        let target = if self.hovered { 1.0 } else { 0.0 };
+
+        #[allow(clippy::float_cmp)]
+        if self.progress == target || !in_view {
+            self.last_frame = None;
+            return false;
+        }
+
+        let elapsed = self.last_frame.map_or(Duration::ZERO, |last| {
+            now.saturating_duration_since(last).min(MAX_FRAME_TIME)
+        });
+
+        let step = if duration.is_zero() {
+            1.0
+        } else {
+            elapsed.as_secs_f32() / duration.as_secs_f32()
+        };
+
+        self.progress = if self.hovered {
+            (self.progress + step).min(1.0)
+        } else {
+            (self.progress - step).max(0.0)
+        };
+
+        self.last_frame = Some(now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+    use iced_core::time::{Duration, Instant};
+
+    const DURATION: Duration = Duration::from_millis(100);
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_fade_in_and_out() {
+        let start = Instant::now();
+        let mut state = State {
+            hovered: true,
+            ..State::default()
+        };
+
+        // The first frame only marks the start of the fade.
+        assert!(state.frame(start, DURATION, true));
+        assert_eq!(state.progress, 0.0);
+
+        assert!(state.frame(start + ms(50), DURATION, true));
+        assert!((state.progress - 0.5).abs() < 0.01);
+
+        // Leaving mid-fade reverses from where it was.
+        state.hovered = false;
+        assert!(state.frame(start + ms(75), DURATION, true));
+        assert!((state.progress - 0.25).abs() < 0.01);
+
+        assert!(state.frame(start + ms(150), DURATION, true));
+        assert_eq!(state.progress, 0.0);
+
+        // Finished, so no more frames are requested.
+        assert!(!state.frame(start + ms(160), DURATION, true));
+    }
+
+    #[test]
+    fn test_fade_pauses_out_of_view() {
+        let start = Instant::now();
+        let mut state = State {
+            hovered: true,
+            ..State::default()
+        };
+
+        assert!(state.frame(start, DURATION, true));
+        assert!(state.frame(start + ms(25), DURATION, true));
+        let progress = state.progress;
+
+        assert!(!state.frame(start + ms(50), DURATION, false));
+        assert_eq!(state.progress, progress);
+
+        // Back in view, the time spent out of view is not counted.
+        assert!(state.frame(start + ms(500), DURATION, true));
+        assert_eq!(state.progress, progress);
+
+        assert!(state.frame(start + ms(525), DURATION, true));
+        assert!((state.progress - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_zero_duration_switches_instantly() {
+        let mut state = State {
+            hovered: true,
+            ..State::default()
+        };
+
+        assert!(state.frame(Instant::now(), Duration::ZERO, true));
+        assert_eq!(state.progress, 1.0);
+    }
+}
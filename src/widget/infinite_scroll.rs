@@ -0,0 +1,355 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Content which requests more of itself when scrolled near its end.
+
+use crate::{Element, Renderer};
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::layout::flex::{self, Axis};
+use iced_core::widget::{tree, Operation, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, Alignment, Clipboard, Layout, Length, Padding, Rectangle,
+    Shell, Size, Widget,
+};
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+/// Content which requests more of itself when scrolled near its end.
+///
+/// Place inside of a scrollable, such as a feed which loads one page at a time.
+pub fn infinite_scroll<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    on_load_more: Message,
+) -> InfiniteScroll<'a, Message> {
+    InfiniteScroll::new(content, on_load_more)
+}
+
+/// Content which requests more of itself when scrolled near its end.
+///
+/// When the bottom of the viewport comes within `threshold` of the end of the content,
+/// `on_load_more` is emitted. It is emitted once per length of content: another request is only
+/// made after new content has arrived and changed its height, so a failed load is not retried
+/// until the application asks for it, such as from a retry button in the footer. Nothing is
+/// requested while `loading`, or once `exhausted`.
+///
+/// The [`footer`](Self::footer), such as a loading indicator or a retry button, is shown below
+/// the content until the content is exhausted.
+#[must_use]
+#[derive(Setters)]
+pub struct InfiniteScroll<'a, Message> {
+    /// The content, followed by the footer if it is shown.
+    #[setters(skip)]
+    children: Vec<Element<'a, Message>>,
+    #[setters(skip)]
+    on_load_more: Message,
+    /// Whether more content is being loaded.
+    loading: bool,
+    #[setters(skip)]
+    exhausted: bool,
+    /// Distance from the end of the content at which more is requested.
+    threshold: f32,
+    /// Sets the padding around the widget.
+    padding: Padding,
+    /// Sets the space between the content and the footer.
+    spacing: u16,
+    /// Alignment of the content and footer along the cross axis.
+    align_items: Alignment,
+    /// Sets the width of the widget.
+    width: Length,
+}
+
+impl<'a, Message> InfiniteScroll<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>, on_load_more: Message) -> Self {
+        Self {
+            children: vec![content.into()],
+            on_load_more,
+            loading: false,
+            exhausted: false,
+            threshold: 200.0,
+            padding: Padding::ZERO,
+            spacing: 0,
+            align_items: Alignment::Start,
+            width: Length::Shrink,
+        }
+    }
+
+    /// Shown below the content until it is exhausted.
+    pub fn footer(mut self, footer: impl Into<Element<'a, Message>>) -> Self {
+        self.children.truncate(1);
+
+        if !self.exhausted {
+            self.children.push(footer.into());
+        }
+
+        self
+    }
+
+    /// Whether there is no more content to load, which also hides the footer.
+    pub fn exhausted(mut self, exhausted: bool) -> Self {
+        self.exhausted = exhausted;
+
+        if exhausted {
+            self.children.truncate(1);
+        }
+
+        self
+    }
+}
+
+impl<'a, Message: 'static + Clone> Widget<Message, crate::Theme, Renderer>
+    for InfiniteScroll<'a, Message>
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(self.children.as_mut_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        flex::resolve(
+            Axis::Vertical,
+            renderer,
+            limits,
+            self.width,
+            Length::Shrink,
+            self.padding,
+            f32::from(self.spacing),
+            self.align_items,
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let content = layout
+            .children()
+            .next()
+            .map_or(layout.bounds(), |c| c.bounds());
+        let state = tree.state.downcast_mut::<State>();
+
+        if !self.loading && !self.exhausted && state.request(content, viewport, self.threshold) {
+            shell.publish(self.on_load_more.clone());
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| c.as_widget().a11y_nodes(c_layout, state, p)),
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_style::core::clipboard::DndDestinationRectangles,
+    ) {
+        for ((e, layout), state) in self
+            .children
+            .iter()
+            .zip(layout.children())
+            .zip(state.children.iter())
+        {
+            e.as_widget()
+                .drag_destinations(state, layout, dnd_rectangles);
+        }
+    }
+}
+
+impl<'a, Message: 'static + Clone> From<InfiniteScroll<'a, Message>> for Element<'a, Message> {
+    fn from(infinite_scroll: InfiniteScroll<'a, Message>) -> Self {
+        Self::new(infinite_scroll)
+    }
+}
+
+/// The height of the content when more was last requested.
+#[derive(Default)]
+struct State {
+    requested_at: Option<f32>,
+}
+
+impl State {
+    /// Whether more should be requested, with the viewport within `threshold` of the end of
+    /// `content`, and nothing yet requested for its current height.
+    fn request(&mut self, content: Rectangle, viewport: &Rectangle, threshold: f32) -> bool {
+        let near_end = viewport.y + viewport.height + threshold >= content.y + content.height;
+
+        if !near_end || self.requested_at == Some(content.height) {
+            return false;
+        }
+
+        self.requested_at = Some(content.height);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+    use iced_core::{Point, Rectangle, Size};
+
+    const THRESHOLD: f32 = 200.0;
+
+    fn content(height: f32) -> Rectangle {
+        Rectangle::new(Point::ORIGIN, Size::new(400.0, height))
+    }
+
+    fn viewport(y: f32) -> Rectangle {
+        Rectangle::new(Point::new(0.0, y), Size::new(400.0, 600.0))
+    }
+
+    #[test]
+    fn test_request_near_end() {
+        let mut state = State::default();
+
+        assert!(!state.request(content(2000.0), &viewport(0.0), THRESHOLD));
+        assert!(!state.request(content(2000.0), &viewport(1100.0), THRESHOLD));
+        assert!(state.request(content(2000.0), &viewport(1200.0), THRESHOLD));
+    }
+
+    #[test]
+    fn test_request_once_per_height() {
+        let mut state = State::default();
+
+        assert!(state.request(content(2000.0), &viewport(1300.0), THRESHOLD));
+
+        // Scrolling further, or a failed load, does not request again.
+        assert!(!state.request(content(2000.0), &viewport(1350.0), THRESHOLD));
+        assert!(!state.request(content(2000.0), &viewport(1400.0), THRESHOLD));
+
+        // New content arrived, and its end is reached again.
+        assert!(!state.request(content(3000.0), &viewport(1400.0), THRESHOLD));
+        assert!(state.request(content(3000.0), &viewport(2400.0), THRESHOLD));
+    }
+}
@@ -0,0 +1,326 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Repeat a message at a fixed rate while a key is held.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    keyboard, layout, mouse, overlay, renderer, window, Clipboard, Layout, Length, Rectangle,
+    Shell, Size, Widget,
+};
+
+use crate::widget::common::HasFocus;
+use crate::{Element, Renderer};
+
+/// Emits `on_repeat` when `key` is pressed, and at a fixed rate for as long as it is held.
+pub fn key_repeat<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    key: keyboard::Key,
+    on_repeat: Message,
+) -> KeyRepeat<'a, Message> {
+    KeyRepeat::new(content, key, on_repeat)
+}
+
+/// Emits a message when a key is pressed, and at a fixed rate for as long as it is held.
+///
+/// The rate is independent of the key repeat settings of the system, whose repeated key
+/// presses are ignored. The key is only handled while a widget in the content has focus, and
+/// not when the content captures it. Repeating stops as soon as the key is released, or the
+/// window loses focus.
+#[derive(Setters)]
+#[must_use]
+pub struct KeyRepeat<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    #[setters(skip)]
+    key: keyboard::Key,
+    #[setters(skip)]
+    on_repeat: Message,
+    /// Time between the key press and the first repeat.
+    delay: Duration,
+    /// Time between each repeat after the first.
+    interval: Duration,
+}
+
+impl<'a, Message> KeyRepeat<'a, Message> {
+    pub fn new(
+        content: impl Into<Element<'a, Message>>,
+        key: keyboard::Key,
+        on_repeat: Message,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            key,
+            on_repeat,
+            delay: Duration::from_millis(400),
+            interval: Duration::from_millis(50),
+        }
+    }
+}
+
+impl<'a, Message: Clone> Widget<Message, crate::Theme, Renderer> for KeyRepeat<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if key == self.key => {
+                if status == event::Status::Captured {
+                    return status;
+                }
+
+                let mut operation = HasFocus::default();
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    &mut operation,
+                );
+
+                if !operation.0 {
+                    return status;
+                }
+
+                let state = tree.state.downcast_mut::<State>();
+
+                // Ignore the key repeat of the system while the key is held.
+                if state.next.is_none() {
+                    let next = Instant::now() + self.delay;
+                    state.next = Some(next);
+                    shell.publish(self.on_repeat.clone());
+                    shell.request_redraw(window::RedrawRequest::At(next));
+                }
+
+                return event::Status::Captured;
+            }
+
+            Event::Keyboard(keyboard::Event::KeyReleased { key, .. }) if key == self.key => {
+                tree.state.downcast_mut::<State>().next = None;
+            }
+
+            Event::Window(_, window::Event::Unfocused) => {
+                tree.state.downcast_mut::<State>().next = None;
+            }
+
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.tick(now, self.interval) {
+                    shell.publish(self.on_repeat.clone());
+                }
+
+                if let Some(next) = state.next {
+                    shell.request_redraw(window::RedrawRequest::At(next));
+                }
+            }
+
+            _ => (),
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: Clone + 'a> From<KeyRepeat<'a, Message>> for Element<'a, Message> {
+    fn from(key_repeat: KeyRepeat<'a, Message>) -> Self {
+        Self::new(key_repeat)
+    }
+}
+
+/// When the next repeat is due, while the key is held.
+#[derive(Default)]
+struct State {
+    next: Option<Instant>,
+}
+
+impl State {
+    /// Whether a repeat is due at `now`, scheduling the one after it.
+    ///
+    /// Repeats missed while no frames were drawn are skipped rather than fired in a burst.
+    fn tick(&mut self, now: Instant, interval: Duration) -> bool {
+        match self.next.as_mut() {
+            Some(next) if now >= *next => {
+                *next = now + interval;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::time::{Duration, Instant};
+
+    use super::State;
+
+    #[test]
+    fn test_tick_cadence() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let interval = ms(50);
+
+        let mut state = State {
+            next: Some(start + ms(400)),
+        };
+
+        let fired: Vec<u64> = [100, 399, 400, 420, 449, 450, 500, 510]
+            .into_iter()
+            .filter(|&t| state.tick(start + ms(t), interval))
+            .collect();
+
+        assert_eq!(fired, vec![400, 450, 500]);
+    }
+
+    #[test]
+    fn test_tick_after_stall() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let interval = ms(50);
+
+        let mut state = State {
+            next: Some(start + ms(400)),
+        };
+
+        assert!(state.tick(start + ms(1000), interval));
+        assert!(!state.tick(start + ms(1001), interval));
+        assert!(!state.tick(start + ms(1049), interval));
+        assert!(state.tick(start + ms(1050), interval));
+    }
+
+    #[test]
+    fn test_tick_after_release() {
+        let start = Instant::now();
+        let mut state = State {
+            next: Some(start + Duration::from_millis(400)),
+        };
+
+        state.next = None;
+
+        assert!(!state.tick(start + Duration::from_secs(1), Duration::from_millis(50)));
+    }
+}
@@ -7,6 +7,7 @@ use iced_core::mouse;
 use iced_core::overlay;
 use iced_core::renderer;
 use iced_core::widget::Tree;
+use iced_core::{border::Radius, Border, Color, Shadow};
 use iced_core::{Clipboard, Element, Layout, Length, Padding, Rectangle, Shell, Widget};
 pub use iced_style::container::{Appearance, StyleSheet};
 
@@ -21,6 +22,32 @@ where
     LayerContainer::new(content)
 }
 
+/// Fills `bounds` with the background color of `layer`.
+///
+/// Containers which draw their own background can call this before drawing their children.
+/// The corners default to the radius of a [`LayerContainer`] when `border_radius` is `None`.
+pub fn draw_layer_background<Renderer: iced_core::Renderer>(
+    renderer: &mut Renderer,
+    theme: &crate::Theme,
+    layer: cosmic_theme::Layer,
+    bounds: Rectangle,
+    border_radius: Option<Radius>,
+) {
+    let cosmic = theme.cosmic();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                radius: border_radius.unwrap_or_else(|| cosmic.corner_radii.radius_xs.into()),
+                ..Border::default()
+            },
+            shadow: Shadow::default(),
+        },
+        Color::from(theme.layer_container(layer).base),
+    );
+}
+
 /// An element decorating some content.
 ///
 /// It is normally used for alignment purposes.
@@ -59,6 +86,7 @@ where
             cosmic_theme::Layer::Background => crate::theme::Container::Background,
             cosmic_theme::Layer::Primary => crate::theme::Container::Primary,
             cosmic_theme::Layer::Secondary => crate::theme::Container::Secondary,
+            cosmic_theme::Layer::Tertiary => crate::theme::Container::Tertiary,
         })
     }
 
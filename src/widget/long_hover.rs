@@ -0,0 +1,355 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Show a help popup in an overlay once a widget has been hovered for a while.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, window, Clipboard, Layout, Length, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+use crate::widget::popover::{place_popover, Side};
+use crate::{Element, Renderer};
+
+/// Shows `popup` below its content once the content has been hovered for a while.
+pub fn long_hover<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    popup: impl Into<Element<'a, Message>>,
+) -> LongHover<'a, Message> {
+    LongHover::new(content, popup)
+}
+
+/// Shows a popup below its content once the content has been hovered for a while.
+///
+/// The popup stays open while the cursor is over either the content or the popup, so that it
+/// may be moved into the popup to interact with it, and closes once the cursor leaves both.
+/// The popup is placed above the content instead if there is no room below, and is kept within
+/// the window.
+#[derive(Setters)]
+#[must_use]
+pub struct LongHover<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    #[setters(skip)]
+    popup: Element<'a, Message>,
+    /// Time the cursor must rest over the content before the popup is shown.
+    delay: Duration,
+    /// Space between the content and the popup.
+    gap: f32,
+}
+
+impl<'a, Message> LongHover<'a, Message> {
+    pub fn new(
+        content: impl Into<Element<'a, Message>>,
+        popup: impl Into<Element<'a, Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            popup: popup.into(),
+            delay: Duration::from_secs(2),
+            gap: 4.0,
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for LongHover<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.popup)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [&mut self.content, &mut self.popup]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                if cursor.is_over(layout.bounds()) {
+                    if state.hover_started.is_none() {
+                        let now = Instant::now();
+                        state.hover_started = Some(now);
+                        shell.request_redraw(window::RedrawRequest::At(now + self.delay));
+                    }
+                } else if !state.over_popup {
+                    state.hover_started = None;
+                    state.is_open = false;
+                }
+            }
+
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                if !state.is_open
+                    && state
+                        .hover_started
+                        .is_some_and(|started| now >= started + self.delay)
+                {
+                    state.is_open = true;
+                    shell.invalidate_layout();
+                }
+            }
+
+            _ => (),
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+        let (content_tree, popup_tree) = children.split_at_mut(1);
+
+        if !state.is_open {
+            return self
+                .content
+                .as_widget_mut()
+                .overlay(&mut content_tree[0], layout, renderer);
+        }
+
+        let host = layout.bounds();
+
+        Some(overlay::Element::new(
+            host.position(),
+            Box::new(Popup {
+                tree: &mut popup_tree[0],
+                content: &mut self.popup,
+                state,
+                host,
+                gap: self.gap,
+            }),
+        ))
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<LongHover<'a, Message>> for Element<'a, Message> {
+    fn from(long_hover: LongHover<'a, Message>) -> Self {
+        Self::new(long_hover)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// When the cursor entered the content.
+    hover_started: Option<Instant>,
+    /// Whether the cursor is over the popup.
+    over_popup: bool,
+    is_open: bool,
+}
+
+struct Popup<'a, 'b, Message> {
+    tree: &'a mut Tree,
+    content: &'a mut Element<'b, Message>,
+    state: &'a mut State,
+    /// Bounds of the content the popup belongs to.
+    host: Rectangle,
+    gap: f32,
+}
+
+impl<'a, 'b, Message> overlay::Overlay<Message, crate::Theme, Renderer> for Popup<'a, 'b, Message> {
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+        translation: Vector,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+        let node = self
+            .content
+            .as_widget()
+            .layout(self.tree, renderer, &limits);
+        let host = self.host + translation;
+
+        // Prefer below the content, falling back to above it when there is no room.
+        let anchor = Rectangle {
+            y: host.y - self.gap,
+            height: host.height + self.gap * 2.0,
+            ..host
+        };
+        let position = place_popover(
+            anchor,
+            node.size(),
+            Rectangle::with_size(bounds),
+            Side::Bottom,
+        );
+
+        node.move_to(Point::new(position.x.round(), position.y.round()))
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(self.tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) = event {
+            self.state.over_popup = cursor.is_over(layout.bounds());
+        }
+
+        self.content.as_widget_mut().on_event(
+            self.tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(self.tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        self.content
+            .as_widget()
+            .draw(self.tree, renderer, theme, style, layout, cursor, &bounds);
+    }
+}
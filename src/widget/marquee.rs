@@ -0,0 +1,322 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Select items by dragging a rectangle over them.
+
+use iced_core::event::{self, Event};
+use iced_core::renderer::Quad;
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    keyboard, layout, mouse, overlay, renderer, Border, Clipboard, Color, Layout, Length, Point,
+    Rectangle, Shadow, Shell, Size, Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Opacity of the fill of the selection rectangle.
+const FILL_ALPHA: f32 = 0.2;
+
+/// Selects the items of its content covered by a rectangle dragged over empty space.
+pub fn marquee<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    on_select: impl Fn(Vec<usize>, bool) -> Message + 'a,
+) -> Marquee<'a, Message> {
+    Marquee::new(content, on_select)
+}
+
+/// Selects the items of its content covered by a rectangle dragged over empty space.
+///
+/// The items are the direct children of the content, such as those of a
+/// [`flex_row`](super::flex_row) or [`grid`](super::grid). Pressing outside of every item and
+/// dragging draws a selection rectangle; on release, `on_select` is emitted with the indices of
+/// the items it intersects, and whether Ctrl was held to add them to the existing selection.
+/// Pressing on an item leaves the press to the item.
+#[must_use]
+pub struct Marquee<'a, Message> {
+    content: Element<'a, Message>,
+    on_select: Box<dyn Fn(Vec<usize>, bool) -> Message + 'a>,
+}
+
+impl<'a, Message> Marquee<'a, Message> {
+    pub fn new(
+        content: impl Into<Element<'a, Message>>,
+        on_select: impl Fn(Vec<usize>, bool) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_select: Box::new(on_select),
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for Marquee<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    // The content shares its layout, so its children are the items.
+                    state.press(position, layout.children().map(|item| item.bounds()));
+                }
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some((_, current)) = state.drag.as_mut() {
+                    *current = position;
+                    shell.request_redraw(iced_core::window::RedrawRequest::NextFrame);
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(selected) = state.release(layout.children().map(|item| item.bounds())) {
+                    shell.publish((self.on_select)(selected, state.modifiers.control()));
+                    shell.request_redraw(iced_core::window::RedrawRequest::NextFrame);
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => (),
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        use iced_core::Renderer as _;
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let Some((origin, current)) = tree.state.downcast_ref::<State>().drag else {
+            return;
+        };
+
+        let accent = Color::from(theme.cosmic().accent_color());
+
+        renderer.with_layer(layout.bounds(), |renderer| {
+            renderer.fill_quad(
+                Quad {
+                    bounds: rectangle(origin, current),
+                    border: Border {
+                        color: accent,
+                        width: 1.0,
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color {
+                    a: FILL_ALPHA,
+                    ..accent
+                },
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<Marquee<'a, Message>> for Element<'a, Message> {
+    fn from(marquee: Marquee<'a, Message>) -> Self {
+        Self::new(marquee)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// Where the drag started, and where the cursor is now.
+    drag: Option<(Point, Point)>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Starts a drag from `position`, unless it is over one of the `items`.
+    fn press(&mut self, position: Point, mut items: impl Iterator<Item = Rectangle>) {
+        if !items.any(|item| item.contains(position)) {
+            self.drag = Some((position, position));
+        }
+    }
+
+    /// Ends the drag, returning the indices of the `items` intersecting its rectangle.
+    fn release(&mut self, items: impl Iterator<Item = Rectangle>) -> Option<Vec<usize>> {
+        let (origin, current) = self.drag.take()?;
+        let region = rectangle(origin, current);
+
+        Some(
+            items
+                .enumerate()
+                .filter(|(_, item)| item.intersects(&region))
+                .map(|(index, _)| index)
+                .collect(),
+        )
+    }
+}
+
+/// The rectangle spanned by two of its corners.
+fn rectangle(a: Point, b: Point) -> Rectangle {
+    Rectangle::new(
+        Point::new(a.x.min(b.x), a.y.min(b.y)),
+        Size::new((a.x - b.x).abs(), (a.y - b.y).abs()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+    use iced_core::{Point, Rectangle, Size};
+
+    /// A row of three 50px items, 10px apart.
+    fn items() -> impl Iterator<Item = Rectangle> {
+        (0..3).map(|i| Rectangle::new(Point::new(i as f32 * 60.0, 0.0), Size::new(50.0, 50.0)))
+    }
+
+    #[test]
+    fn test_drag_selects_intersecting_items() {
+        let mut state = State::default();
+
+        state.press(Point::new(20.0, 80.0), items());
+        state.drag.as_mut().unwrap().1 = Point::new(100.0, 40.0);
+
+        assert_eq!(state.release(items()), Some(vec![0, 1]));
+        assert!(state.drag.is_none());
+    }
+
+    #[test]
+    fn test_drag_in_any_direction() {
+        let mut state = State::default();
+
+        state.press(Point::new(175.0, 55.0), items());
+        state.drag.as_mut().unwrap().1 = Point::new(55.0, 10.0);
+
+        assert_eq!(state.release(items()), Some(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_press_on_item_is_left_to_it() {
+        let mut state = State::default();
+
+        state.press(Point::new(70.0, 20.0), items());
+        assert!(state.drag.is_none());
+        assert_eq!(state.release(items()), None);
+    }
+}
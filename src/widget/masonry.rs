@@ -0,0 +1,352 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A container which packs its children into columns of balanced height.
+
+use crate::{Element, Renderer};
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::widget::{Operation, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, Clipboard, Layout, Length, Padding, Point, Rectangle, Shell,
+    Size, Widget,
+};
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+/// A container which packs its children into columns of balanced height.
+pub fn masonry<'a, Message>(columns: usize) -> Masonry<'a, Message> {
+    Masonry::new(columns)
+}
+
+/// A container which packs its children into columns of balanced height.
+///
+/// Each child is placed in whichever column is currently the shortest, in the order they were
+/// pushed, which suits items of varying heights such as a photo grid. Children are laid out at
+/// the width of a column; those which would be wider are limited to it.
+#[must_use]
+#[derive(Setters)]
+pub struct Masonry<'a, Message> {
+    #[setters(skip)]
+    children: Vec<Element<'a, Message>>,
+    /// The number of columns.
+    columns: usize,
+    /// Sets the padding around the widget.
+    padding: Padding,
+    /// Sets the space between columns, and between children within a column.
+    spacing: u16,
+    /// Sets the width of the container.
+    width: Length,
+}
+
+impl<'a, Message> Masonry<'a, Message> {
+    pub const fn new(columns: usize) -> Self {
+        Self {
+            children: Vec::new(),
+            columns,
+            padding: Padding::ZERO,
+            spacing: 0,
+            width: Length::Fill,
+        }
+    }
+
+    /// Attach a new element to the container.
+    pub fn push(mut self, widget: impl Into<Element<'a, Message>>) -> Self {
+        self.children.push(widget.into());
+        self
+    }
+}
+
+impl<'a, Message: 'static + Clone> Widget<Message, crate::Theme, Renderer>
+    for Masonry<'a, Message>
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(self.children.as_mut_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).shrink(self.padding);
+        let columns = self.columns.max(1);
+        let spacing = f32::from(self.spacing);
+
+        let column_width =
+            ((limits.max().width - spacing * (columns - 1) as f32) / columns as f32).max(0.0);
+        let child_limits = layout::Limits::new(Size::ZERO, Size::new(column_width, f32::INFINITY));
+
+        let nodes: Vec<layout::Node> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| child.as_widget().layout(tree, renderer, &child_limits))
+            .collect();
+
+        // Without a bounded width, columns are as wide as the widest child.
+        let column_width = if column_width.is_finite() {
+            column_width
+        } else {
+            nodes
+                .iter()
+                .map(|node| node.size().width)
+                .fold(0.0, f32::max)
+        };
+
+        let (placements, height) = pack(
+            nodes.iter().map(|node| node.size().height),
+            columns,
+            spacing,
+        );
+
+        let nodes = nodes
+            .into_iter()
+            .zip(placements)
+            .map(|(node, (column, y))| {
+                node.move_to(Point::new(
+                    self.padding.left + column as f32 * (column_width + spacing),
+                    self.padding.top + y,
+                ))
+            })
+            .collect();
+
+        let content = Size::new(
+            column_width * columns as f32 + spacing * (columns - 1) as f32,
+            height,
+        );
+
+        let size = limits
+            .resolve(self.width, Length::Shrink, content)
+            .expand(self.padding);
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| c.as_widget().a11y_nodes(c_layout, state, p)),
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_style::core::clipboard::DndDestinationRectangles,
+    ) {
+        for ((e, layout), state) in self
+            .children
+            .iter()
+            .zip(layout.children())
+            .zip(state.children.iter())
+        {
+            e.as_widget()
+                .drag_destinations(state, layout, dnd_rectangles);
+        }
+    }
+}
+
+impl<'a, Message: 'static + Clone> From<Masonry<'a, Message>> for Element<'a, Message> {
+    fn from(masonry: Masonry<'a, Message>) -> Self {
+        Self::new(masonry)
+    }
+}
+
+/// Places items of the given heights, in order, at the bottom of the shortest of `columns`.
+///
+/// Returns the column and vertical offset of each item, and the height of the tallest column.
+fn pack(
+    heights: impl IntoIterator<Item = f32>,
+    columns: usize,
+    spacing: f32,
+) -> (Vec<(usize, f32)>, f32) {
+    // Each column starts one spacing above the top, so that its first item is placed at zero.
+    let mut bottoms = vec![-spacing; columns.max(1)];
+
+    let placements = heights
+        .into_iter()
+        .map(|height| {
+            let (column, bottom) = bottoms
+                .iter_mut()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .expect("there is at least one column");
+
+            let y = *bottom + spacing;
+            *bottom = y + height;
+
+            (column, y)
+        })
+        .collect();
+
+    (placements, bottoms.into_iter().fold(0.0, f32::max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pack;
+
+    #[test]
+    fn test_pack_shortest_column() {
+        let (placements, height) = pack([100.0, 50.0, 50.0, 100.0, 50.0, 50.0], 3, 0.0);
+
+        assert_eq!(
+            placements,
+            [
+                (0, 0.0),
+                (1, 0.0),
+                (2, 0.0),
+                (1, 50.0),
+                (2, 50.0),
+                (0, 100.0)
+            ]
+        );
+        assert_eq!(height, 150.0);
+    }
+
+    #[test]
+    fn test_pack_balances_columns() {
+        let heights = [100.0, 50.0, 50.0, 100.0, 50.0, 50.0];
+        let (placements, _) = pack(heights, 3, 10.0);
+
+        let mut bottoms = [0.0f32; 3];
+        for ((column, y), height) in placements.into_iter().zip(heights) {
+            bottoms[column] = y + height;
+        }
+
+        // Every column holds two items, and none is taller than another by more than an item.
+        assert_eq!(bottoms, [160.0, 160.0, 110.0]);
+    }
+
+    #[test]
+    fn test_pack_empty() {
+        let (placements, height) = pack(std::iter::empty(), 3, 10.0);
+
+        assert!(placements.is_empty());
+        assert_eq!(height, 0.0);
+    }
+}
@@ -59,6 +59,21 @@ pub use iced::widget::{container, Container};
 #[doc(inline)]
 pub use iced::widget::{horizontal_space, space, vertical_space, Space};
 
+/// The element if there is one, or else an empty [`Space`] in its place.
+///
+/// Unlike leaving the element out, as
+/// [`push_maybe`](crate::prelude::CollectionWidget::push_maybe) does, this keeps the position
+/// of every sibling after it, so their state is kept when the element comes and goes. The
+/// space is still separated from its siblings by the spacing of its container.
+pub fn maybe<'a, Message: 'a>(
+    element: Option<impl Into<crate::Element<'a, Message>>>,
+) -> crate::Element<'a, Message> {
+    element.map_or_else(
+        || Space::new(iced::Length::Fixed(0.0), iced::Length::Fixed(0.0)).into(),
+        Into::into,
+    )
+}
+
 #[doc(inline)]
 pub use iced::widget::{image, Image};
 
@@ -89,14 +104,30 @@ pub use iced::widget::{svg, Svg};
 #[doc(inline)]
 pub use iced_core::widget::{Id, Operation, Widget};
 
+pub mod adaptive;
+#[doc(inline)]
+pub use adaptive::{adaptive, Adaptive};
+
+pub mod animated_size;
+#[doc(inline)]
+pub use animated_size::{animated_size, AnimatedSize};
+
 pub mod aspect_ratio;
 
+pub mod autoscroll;
+#[doc(inline)]
+pub use autoscroll::{autoscroll, Autoscroll};
+
 pub mod button;
 #[doc(inline)]
 pub use button::{button, Button, IconButton, LinkButton, TextButton};
 
 pub(crate) mod common;
 
+pub mod cached_layout;
+#[doc(inline)]
+pub use cached_layout::{cached_layout, CachedLayout};
+
 pub mod calendar;
 #[doc(inline)]
 pub use calendar::{calendar, Calendar};
@@ -105,10 +136,18 @@ pub mod card;
 #[doc(inline)]
 pub use card::*;
 
+pub mod clamped_text;
+#[doc(inline)]
+pub use clamped_text::{clamped_text, ClampedText};
+
 pub mod color_picker;
 #[doc(inline)]
 pub use color_picker::{ColorPicker, ColorPickerModel};
 
+pub mod color_scheme;
+#[doc(inline)]
+pub use color_scheme::{color_scheme, ForcedScheme};
+
 pub mod context_drawer;
 #[doc(inline)]
 pub use context_drawer::{context_drawer, ContextDrawer};
@@ -139,9 +178,13 @@ pub mod column {
     }
 }
 
+pub mod key_repeat;
+#[doc(inline)]
+pub use key_repeat::{key_repeat, KeyRepeat};
+
 pub mod layer_container;
 #[doc(inline)]
-pub use layer_container::{layer_container, LayerContainer};
+pub use layer_container::{draw_layer_background, layer_container, LayerContainer};
 
 pub mod context_menu;
 #[doc(inline)]
@@ -212,22 +255,42 @@ pub mod dropdown;
 #[doc(inline)]
 pub use dropdown::{dropdown, Dropdown};
 
+pub mod file_drop;
+#[doc(inline)]
+pub use file_drop::{file_drop, FileDrop};
+
+pub mod fit_text;
+#[doc(inline)]
+pub use fit_text::{fit_text, FitText};
+
 pub mod flex_row;
 #[doc(inline)]
 pub use flex_row::{flex_row, FlexRow};
 
+pub mod focus_within;
+#[doc(inline)]
+pub use focus_within::{focus_within, FocusWithin};
+
 pub mod grid;
 #[doc(inline)]
 pub use grid::{grid, Grid};
 
 mod header_bar;
 #[doc(inline)]
-pub use header_bar::{header_bar, HeaderBar};
+pub use header_bar::{header_bar, HeaderBar, TiledEdges};
+
+pub mod hover_fade;
+#[doc(inline)]
+pub use hover_fade::{hover_fade, HoverFade};
 
 pub mod icon;
 #[doc(inline)]
 pub use icon::{icon, Icon};
 
+pub mod infinite_scroll;
+#[doc(inline)]
+pub use infinite_scroll::{infinite_scroll, InfiniteScroll};
+
 #[cfg(feature = "animated-image")]
 pub mod frames;
 
@@ -235,6 +298,18 @@ pub mod list;
 #[doc(inline)]
 pub use list::{list_column, ListColumn};
 
+pub mod long_hover;
+#[doc(inline)]
+pub use long_hover::{long_hover, LongHover};
+
+pub mod marquee;
+#[doc(inline)]
+pub use marquee::{marquee, Marquee};
+
+pub mod masonry;
+#[doc(inline)]
+pub use masonry::{masonry, Masonry};
+
 pub mod menu;
 
 pub mod nav_bar;
@@ -245,6 +320,10 @@ pub mod nav_bar_toggle;
 #[doc(inline)]
 pub use nav_bar_toggle::{nav_bar_toggle, NavBarToggle};
 
+pub mod navigate_back;
+#[doc(inline)]
+pub use navigate_back::{navigate_back, NavigateBack};
+
 pub mod popover;
 #[doc(inline)]
 pub use popover::{popover, Popover};
@@ -253,9 +332,21 @@ pub mod rectangle_tracker;
 #[doc(inline)]
 pub use rectangle_tracker::{rectangle_tracker, RectangleTracker};
 
+pub mod resize_settle;
+#[doc(inline)]
+pub use resize_settle::{resize_settle, ResizeSettle};
+
+pub mod rich_text;
+#[doc(inline)]
+pub use rich_text::{rich_text, RichText};
+
 #[doc(inline)]
 pub use row::{row, Row};
 
+pub mod ripple;
+#[doc(inline)]
+pub use ripple::{ripple, Ripple};
+
 pub mod row {
     //! A container which aligns its children in a row.
 
@@ -284,15 +375,35 @@ mod scrollable;
 #[doc(inline)]
 pub use scrollable::*;
 
+pub mod sectioned;
+#[doc(inline)]
+pub use sectioned::{sectioned, Sectioned};
+
 pub mod segmented_button;
 pub mod segmented_control;
 
+pub mod selection_list;
+#[doc(inline)]
+pub use selection_list::{selection_list, SelectionList};
+
 pub mod settings;
 
+pub mod skeleton;
+#[doc(inline)]
+pub use skeleton::{skeleton, Skeleton};
+
 pub mod spin_button;
 #[doc(inline)]
 pub use spin_button::{spin_button, SpinButton};
 
+pub mod stack;
+#[doc(inline)]
+pub use stack::{stack, Stack};
+
+pub mod swipeable;
+#[doc(inline)]
+pub use swipeable::{swipeable, Swipeable};
+
 pub mod tab_bar;
 
 pub mod text;
@@ -305,6 +416,14 @@ pub use text_input::{
     editable_input, inline_input, search_input, secure_input, text_input, TextInput,
 };
 
+pub mod throttle;
+#[doc(inline)]
+pub use throttle::{throttle, Throttle};
+
+pub mod toaster;
+#[doc(inline)]
+pub use toaster::{toaster, ToastId, Toaster, Toasts};
+
 mod toggler;
 #[doc(inline)]
 pub use toggler::toggler;
@@ -337,3 +456,7 @@ pub mod tooltip {
 pub mod warning;
 #[doc(inline)]
 pub use warning::*;
+
+pub mod zoomable;
+#[doc(inline)]
+pub use zoomable::{zoomable, Zoomable};
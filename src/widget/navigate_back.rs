@@ -0,0 +1,264 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Navigate back with the mouse back button or keyboard shortcut.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::widget::{Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    keyboard, layout, mouse, overlay, renderer, Clipboard, Layout, Length, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Emits `on_back` when back navigation is requested over its content.
+pub fn navigate_back<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+) -> NavigateBack<'a, Message> {
+    NavigateBack::new(content)
+}
+
+/// Emits a message when back navigation is requested over its content.
+///
+/// Back is requested by pressing the back button of the mouse over the content, or by
+/// pressing Alt+Left or the browser back key while the content does not capture them.
+/// Requests are ignored while `on_back` is unset, such as on the first page of a navigation
+/// stack. Edge swipes are detected with [`swipeable`](super::swipeable).
+#[derive(Setters)]
+#[must_use]
+pub struct NavigateBack<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    /// The message emitted when back navigation is requested.
+    #[setters(strip_option)]
+    on_back: Option<Message>,
+}
+
+impl<'a, Message> NavigateBack<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            content: content.into(),
+            on_back: None,
+        }
+    }
+}
+
+impl<'a, Message: Clone> Widget<Message, crate::Theme, Renderer> for NavigateBack<'a, Message> {
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let Some(on_back) = self.on_back.as_ref() else {
+            return status;
+        };
+
+        if is_back(&event, cursor, layout.bounds()) {
+            shell.publish(on_back.clone());
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: Clone + 'a> From<NavigateBack<'a, Message>> for Element<'a, Message> {
+    fn from(navigate_back: NavigateBack<'a, Message>) -> Self {
+        Self::new(navigate_back)
+    }
+}
+
+/// Whether `event` asks to navigate back, with the cursor over `bounds` for the mouse.
+fn is_back(event: &Event, cursor: mouse::Cursor, bounds: Rectangle) -> bool {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Back)) => cursor.is_over(bounds),
+
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modifiers,
+            ..
+        }) => match named {
+            keyboard::key::Named::ArrowLeft => modifiers.alt(),
+            keyboard::key::Named::BrowserBack => true,
+            _ => false,
+        },
+
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_back;
+    use iced_core::keyboard::{self, key::Named, Modifiers};
+    use iced_core::{mouse, Event, Point, Rectangle, Size};
+
+    fn bounds() -> Rectangle {
+        Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0))
+    }
+
+    fn key(named: Named, modifiers: Modifiers) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            location: keyboard::Location::Standard,
+            modifiers,
+            text: None,
+        })
+    }
+
+    #[test]
+    fn test_back_mouse_button() {
+        let back = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Back));
+        let inside = mouse::Cursor::Available(Point::new(50.0, 50.0));
+        let outside = mouse::Cursor::Available(Point::new(150.0, 50.0));
+
+        assert!(is_back(&back, inside, bounds()));
+        assert!(!is_back(&back, outside, bounds()));
+
+        let left = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        assert!(!is_back(&left, inside, bounds()));
+    }
+
+    #[test]
+    fn test_alt_left() {
+        let cursor = mouse::Cursor::Unavailable;
+
+        assert!(is_back(
+            &key(Named::ArrowLeft, Modifiers::ALT),
+            cursor,
+            bounds()
+        ));
+        assert!(!is_back(
+            &key(Named::ArrowLeft, Modifiers::empty()),
+            cursor,
+            bounds()
+        ));
+    }
+
+    #[test]
+    fn test_browser_back() {
+        assert!(is_back(
+            &key(Named::BrowserBack, Modifiers::empty()),
+            mouse::Cursor::Unavailable,
+            bounds()
+        ));
+    }
+}
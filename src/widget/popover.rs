@@ -9,7 +9,7 @@ use iced_core::mouse;
 use iced_core::overlay;
 use iced_core::renderer;
 use iced_core::touch;
-use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::widget::{operation, tree, Id, Operation, OperationOutputWrapper, Tree};
 use iced_core::{
     Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector, Widget,
 };
@@ -31,6 +31,92 @@ pub enum Position {
     Point(Point),
 }
 
+/// A side of an anchor which a popover is placed against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// Positions a popover against the `preferred` side of `anchor`, centered along that side.
+///
+/// When the popover does not fit within `viewport` on the preferred side, it flips to the
+/// opposite side if it fits there. It is then shifted to stay within `viewport`. A popover
+/// larger than `viewport` is aligned to its top left, leaving the rest to scroll or be clipped.
+#[must_use]
+pub fn place_popover(
+    anchor: Rectangle,
+    popover: Size,
+    viewport: Rectangle,
+    preferred: Side,
+) -> Point {
+    let on_side = |side| match side {
+        Side::Top => Point::new(
+            anchor.center_x() - popover.width / 2.0,
+            anchor.y - popover.height,
+        ),
+        Side::Bottom => Point::new(
+            anchor.center_x() - popover.width / 2.0,
+            anchor.y + anchor.height,
+        ),
+        Side::Left => Point::new(
+            anchor.x - popover.width,
+            anchor.center_y() - popover.height / 2.0,
+        ),
+        Side::Right => Point::new(
+            anchor.x + anchor.width,
+            anchor.center_y() - popover.height / 2.0,
+        ),
+    };
+
+    let fits = |position: Point| match preferred {
+        Side::Top | Side::Bottom => {
+            position.y >= viewport.y && position.y + popover.height <= viewport.y + viewport.height
+        }
+        Side::Left | Side::Right => {
+            position.x >= viewport.x && position.x + popover.width <= viewport.x + viewport.width
+        }
+    };
+
+    let mut position = on_side(preferred);
+
+    if !fits(position) {
+        let flipped = on_side(preferred.opposite());
+        if fits(flipped) {
+            position = flipped;
+        }
+    }
+
+    // Unlike `f32::clamp`, the minimum wins when the popover is larger than the viewport.
+    let clamp = |value: f32, min: f32, max: f32| value.min(max).max(min);
+
+    Point::new(
+        clamp(
+            position.x,
+            viewport.x,
+            viewport.x + viewport.width - popover.width,
+        ),
+        clamp(
+            position.y,
+            viewport.y,
+            viewport.y + viewport.height - popover.height,
+        ),
+    )
+}
+
 #[must_use]
 pub struct Popover<'a, Message, Renderer> {
     content: Element<'a, Message, crate::Theme, Renderer>,
@@ -51,6 +137,11 @@ impl<'a, Message, Renderer> Popover<'a, Message, Renderer> {
     }
 
     /// A modal popup interrupts user inputs and demands action.
+    ///
+    /// While a modal popup is shown, focus is taken from the content and keyboard navigation
+    /// is confined to the popup. When it closes, focus returns to the widget which held it
+    /// before, or the first focusable widget of the content if that widget can no longer be
+    /// found. Nothing is focused if nothing had focus when the popup opened.
     pub fn modal(mut self, modal: bool) -> Self {
         self.modal = modal;
         self
@@ -79,7 +170,15 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State { is_open: true })
+        tree::State::new(State {
+            is_open: true,
+            popup_shown: self.popup.is_some(),
+            focus: if self.modal && self.popup.is_some() {
+                FocusRestore::Capture
+            } else {
+                FocusRestore::Idle
+            },
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -91,6 +190,18 @@ where
     }
 
     fn diff(&mut self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let shown = self.popup.is_some();
+
+        if shown != state.popup_shown {
+            state.popup_shown = shown;
+            state.focus = match std::mem::take(&mut state.focus) {
+                _ if shown && self.modal => FocusRestore::Capture,
+                FocusRestore::Trapped(id) if !shown => FocusRestore::Restore(id),
+                _ => FocusRestore::Idle,
+            };
+        }
+
         if let Some(popup) = &mut self.popup {
             tree.diff_children(&mut [&mut self.content, &mut popup.borrow_mut()]);
         } else {
@@ -119,6 +230,20 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
     ) {
+        // Keep focus within the popup while it is shown, but let other operations through.
+        if matches!(
+            tree.state.downcast_ref::<State>().focus,
+            FocusRestore::Trapped(_)
+        ) {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut WithoutFocus(operation),
+            );
+            return;
+        }
+
         self.content
             .as_widget()
             .operate(&mut tree.children[0], layout, renderer, operation);
@@ -135,6 +260,38 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match std::mem::take(&mut state.focus) {
+            FocusRestore::Capture => {
+                let mut operation = TakeFocus(None);
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    &mut operation,
+                );
+                tree.state.downcast_mut::<State>().focus = FocusRestore::Trapped(operation.0);
+            }
+
+            FocusRestore::Restore(previous) => {
+                let restored = restore_focus(previous, |operation| {
+                    self.content.as_widget().operate(
+                        &mut tree.children[0],
+                        layout,
+                        renderer,
+                        operation,
+                    );
+                });
+
+                if restored {
+                    shell.request_redraw(iced_core::window::RedrawRequest::NextFrame);
+                }
+            }
+
+            focus => state.focus = focus,
+        }
+
         if !self.modal
             && matches!(
                 event,
@@ -234,6 +391,7 @@ where
                     tree: &mut tree.children[1],
                     content: popup,
                     position: self.position,
+                    anchor: bounds,
                 }),
             ))
         } else {
@@ -270,6 +428,7 @@ pub struct Overlay<'a, 'b, Message, Renderer> {
     tree: &'a mut Tree,
     content: &'a RefCell<Element<'b, Message, crate::Theme, Renderer>>,
     position: Position,
+    anchor: Rectangle,
 }
 
 impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, crate::Theme, Renderer>
@@ -282,7 +441,7 @@ where
         renderer: &Renderer,
         bounds: Size,
         mut position: Point,
-        _translation: iced::Vector,
+        translation: iced::Vector,
     ) -> layout::Node {
         let limits = layout::Limits::new(Size::UNIT, bounds);
         let node = self
@@ -299,17 +458,24 @@ where
                 position.y = (position.y - height / 2.0).clamp(0.0, bounds.height - height);
             }
             Position::Bottom => {
-                // Position is set to the center bottom of the widget
-                let width = node.size().width;
-                position.x = (position.x - width / 2.0).clamp(0.0, bounds.width - width);
+                // Position is below the widget, or above it if there is no room below
+                position = place_popover(
+                    self.anchor + translation,
+                    node.size(),
+                    Rectangle::with_size(bounds),
+                    Side::Bottom,
+                );
             }
             Position::Point(_) => {
-                // Position is using context menu logic
+                // Position is using context menu logic, with an anchor as wide as the popup
+                // aligning its left edge to the point
                 let size = node.size();
-                position.x = position.x.clamp(0.0, bounds.width - size.width);
-                if position.y + size.height > bounds.height {
-                    position.y = (position.y - size.height).clamp(0.0, bounds.height - size.height);
-                }
+                position = place_popover(
+                    Rectangle::new(position, Size::new(size.width, 0.0)),
+                    size,
+                    Rectangle::with_size(bounds),
+                    Side::Bottom,
+                );
             }
         }
 
@@ -394,4 +560,298 @@ where
 #[derive(Debug, Default)]
 struct State {
     is_open: bool,
+    popup_shown: bool,
+    focus: FocusRestore,
+}
+
+/// Progress of moving focus into a modal popup and back out of it.
+#[derive(Debug, Default)]
+enum FocusRestore {
+    #[default]
+    Idle,
+    /// The popup was opened, and focus must be taken from the content.
+    Capture,
+    /// The popup is shown, holding the widget which had focus before, if any.
+    Trapped(Option<Previous>),
+    /// The popup was closed, and focus must return to the widget which had it, if any.
+    Restore(Option<Previous>),
+}
+
+/// The widget which had focus when a modal popup opened.
+#[derive(Debug, PartialEq)]
+enum Previous {
+    /// The widget with this id.
+    Id(Id),
+    /// A widget without an id, which cannot be found again.
+    Anonymous,
+}
+
+/// Returns focus to the widget which had it before a modal popup opened, returning whether
+/// any widget was focused.
+///
+/// Falls back to the first focusable widget if the widget which had focus cannot be found.
+fn restore_focus(previous: Option<Previous>, mut operate: impl FnMut(&mut Focus)) -> bool {
+    let target = match previous {
+        None => return false,
+        Some(Previous::Id(id)) => Some(id),
+        Some(Previous::Anonymous) => None,
+    };
+
+    let mut operation = Focus {
+        target,
+        found: false,
+    };
+
+    operate(&mut operation);
+
+    if !operation.found && operation.target.is_some() {
+        operation.target = None;
+        operate(&mut operation);
+    }
+
+    operation.found
+}
+
+/// Unfocuses the focused widget, recording it.
+struct TakeFocus(Option<Previous>);
+
+impl<T> Operation<T> for TakeFocus {
+    fn focusable(&mut self, state: &mut dyn operation::Focusable, id: Option<&Id>) {
+        if state.is_focused() {
+            self.0 = Some(id.cloned().map_or(Previous::Anonymous, Previous::Id));
+            state.unfocus();
+        }
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+}
+
+/// Focuses the widget with the `target` id, or the first focusable widget without one.
+struct Focus {
+    target: Option<Id>,
+    found: bool,
+}
+
+impl<T> Operation<T> for Focus {
+    fn focusable(&mut self, state: &mut dyn operation::Focusable, id: Option<&Id>) {
+        if !self.found && (self.target.is_none() || self.target.as_ref() == id) {
+            self.found = true;
+            state.focus();
+        }
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if !self.found {
+            operate_on_children(self);
+        }
+    }
+}
+
+/// Passes an operation through, except for focus.
+struct WithoutFocus<'a, T>(&'a mut dyn Operation<T>);
+
+impl<'a, T> Operation<T> for WithoutFocus<'a, T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.0.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut WithoutFocus(operation));
+        });
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.0.scrollable(state, id, bounds, translation);
+    }
+
+    fn text_input(&mut self, state: &mut dyn operation::TextInput, id: Option<&Id>) {
+        self.0.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+        self.0.custom(state, id);
+    }
+
+    fn finish(&self) -> operation::Outcome<T> {
+        self.0.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::widget::{operation, Id, Operation};
+    use iced_core::{Point, Rectangle, Size};
+
+    use super::{place_popover, restore_focus, Focus, Previous, Side, TakeFocus};
+
+    #[derive(Default)]
+    struct Focusable {
+        focused: bool,
+    }
+
+    impl operation::Focusable for Focusable {
+        fn is_focused(&self) -> bool {
+            self.focused
+        }
+
+        fn focus(&mut self) {
+            self.focused = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.focused = false;
+        }
+    }
+
+    /// Focusable widgets, and their ids.
+    fn widgets(ids: &[Option<&'static str>]) -> Vec<(Focusable, Option<Id>)> {
+        ids.iter()
+            .map(|id| (Focusable::default(), id.map(Id::new)))
+            .collect()
+    }
+
+    fn operate<O: Operation<()>>(widgets: &mut [(Focusable, Option<Id>)], operation: &mut O) {
+        for (state, id) in widgets {
+            operation.focusable(state, id.as_ref());
+        }
+    }
+
+    fn focused(widgets: &[(Focusable, Option<Id>)]) -> Vec<bool> {
+        widgets.iter().map(|(state, _)| state.focused).collect()
+    }
+
+    #[test]
+    fn test_take_focus() {
+        let mut content = widgets(&[Some("a"), Some("b")]);
+        content[1].0.focused = true;
+
+        let mut operation = TakeFocus(None);
+        operate(&mut content, &mut operation);
+
+        assert_eq!(operation.0, Some(Previous::Id(Id::new("b"))));
+        assert_eq!(focused(&content), [false, false]);
+    }
+
+    #[test]
+    fn test_restore_focus() {
+        let mut content = widgets(&[Some("a"), Some("b")]);
+
+        assert!(restore_focus(
+            Some(Previous::Id(Id::new("b"))),
+            |op: &mut Focus| {
+                operate(&mut content, op);
+            }
+        ));
+        assert_eq!(focused(&content), [false, true]);
+    }
+
+    #[test]
+    fn test_restore_nothing_focused() {
+        let mut content = widgets(&[Some("a"), None]);
+
+        assert!(!restore_focus(None, |op: &mut Focus| operate(
+            &mut content,
+            op
+        )));
+        assert_eq!(focused(&content), [false, false]);
+    }
+
+    #[test]
+    fn test_restore_missing_widget() {
+        let mut content = widgets(&[None, Some("a")]);
+
+        assert!(restore_focus(
+            Some(Previous::Id(Id::new("gone"))),
+            |op: &mut Focus| {
+                operate(&mut content, op);
+            }
+        ));
+        assert_eq!(focused(&content), [true, false]);
+    }
+
+    #[test]
+    fn test_restore_anonymous_widget() {
+        let mut content = widgets(&[None, Some("a")]);
+
+        assert!(restore_focus(
+            Some(Previous::Anonymous),
+            |op: &mut Focus| {
+                operate(&mut content, op);
+            }
+        ));
+        assert_eq!(focused(&content), [true, false]);
+    }
+
+    const VIEWPORT: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 400.0,
+        height: 300.0,
+    };
+
+    fn place(anchor: Rectangle, popover: Size) -> Point {
+        place_popover(anchor, popover, VIEWPORT, Side::Bottom)
+    }
+
+    #[test]
+    fn test_place_popover_below() {
+        let anchor = Rectangle::new(Point::new(100.0, 100.0), Size::new(50.0, 20.0));
+
+        assert_eq!(
+            place(anchor, Size::new(80.0, 40.0)),
+            Point::new(85.0, 120.0)
+        );
+    }
+
+    #[test]
+    fn test_place_popover_flips_without_room_below() {
+        let anchor = Rectangle::new(Point::new(100.0, 260.0), Size::new(50.0, 20.0));
+
+        assert_eq!(
+            place(anchor, Size::new(80.0, 40.0)),
+            Point::new(85.0, 220.0)
+        );
+    }
+
+    #[test]
+    fn test_place_popover_stays_without_room_on_either_side() {
+        // Too tall for either side, so it stays below and is shifted up into the viewport.
+        let anchor = Rectangle::new(Point::new(100.0, 140.0), Size::new(50.0, 20.0));
+
+        assert_eq!(
+            place(anchor, Size::new(80.0, 200.0)),
+            Point::new(85.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_place_popover_clamps_to_edges() {
+        let size = Size::new(80.0, 40.0);
+
+        let left = Rectangle::new(Point::new(0.0, 100.0), Size::new(20.0, 20.0));
+        assert_eq!(place(left, size), Point::new(0.0, 120.0));
+
+        let right = Rectangle::new(Point::new(380.0, 100.0), Size::new(20.0, 20.0));
+        assert_eq!(place(right, size), Point::new(320.0, 120.0));
+    }
 }
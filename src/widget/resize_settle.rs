@@ -0,0 +1,269 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Emit a message once the size of a widget has stopped changing.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, window, Clipboard, Layout, Length, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Emits `on_resize_end` with the size of its content once it has stopped changing.
+pub fn resize_settle<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    on_resize_end: impl Fn(Size) -> Message + 'a,
+) -> ResizeSettle<'a, Message> {
+    ResizeSettle::new(content, on_resize_end)
+}
+
+/// Emits a message with the size of its content once it has stopped changing.
+///
+/// The message is emitted when the size has been the same for a number of
+/// [`frames`](Self::frames), so expensive work can wait for a resize to finish. It is emitted
+/// once for the initial size, and once each time a resize settles on a new size.
+#[derive(Setters)]
+#[must_use]
+pub struct ResizeSettle<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    #[setters(skip)]
+    on_resize_end: Box<dyn Fn(Size) -> Message + 'a>,
+    /// Number of frames the size must be unchanged for before emitting.
+    frames: u32,
+}
+
+impl<'a, Message> ResizeSettle<'a, Message> {
+    pub fn new(
+        content: impl Into<Element<'a, Message>>,
+        on_resize_end: impl Fn(Size) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_resize_end: Box::new(on_resize_end),
+            frames: 6,
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for ResizeSettle<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_, window::Event::RedrawRequested(_)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            let size = layout.bounds().size();
+
+            if let Some(size) = state.observe(size, self.frames) {
+                shell.publish((self.on_resize_end)(size));
+            } else if state.reported != Some(size) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<ResizeSettle<'a, Message>> for Element<'a, Message> {
+    fn from(resize_settle: ResizeSettle<'a, Message>) -> Self {
+        Self::new(resize_settle)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// The size as of the last frame.
+    size: Size,
+    /// Number of frames the size has been unchanged for.
+    stable_frames: u32,
+    /// The size last emitted.
+    reported: Option<Size>,
+}
+
+impl State {
+    /// Records the size drawn in a frame.
+    ///
+    /// Returns the size once it has been unchanged for `frames` frames, and not yet reported.
+    fn observe(&mut self, size: Size, frames: u32) -> Option<Size> {
+        if self.size != size {
+            self.size = size;
+            self.stable_frames = 0;
+        }
+
+        if self.reported == Some(size) {
+            return None;
+        }
+
+        self.stable_frames += 1;
+
+        if self.stable_frames >= frames {
+            self.reported = Some(size);
+            Some(size)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+    use iced_core::Size;
+
+    const FRAMES: u32 = 3;
+
+    #[test]
+    fn test_changing_size_never_settles() {
+        let mut state = State::default();
+
+        for width in 1..20 {
+            let size = Size::new(width as f32, 100.0);
+            assert_eq!(state.observe(size, FRAMES), None);
+        }
+    }
+
+    #[test]
+    fn test_stable_size_settles_once() {
+        let mut state = State::default();
+        let size = Size::new(200.0, 100.0);
+
+        assert_eq!(state.observe(size, FRAMES), None);
+        assert_eq!(state.observe(size, FRAMES), None);
+        assert_eq!(state.observe(size, FRAMES), Some(size));
+
+        for _ in 0..10 {
+            assert_eq!(state.observe(size, FRAMES), None);
+        }
+
+        // A resize settling on a new size is reported again.
+        let resized = Size::new(300.0, 100.0);
+        assert_eq!(state.observe(resized, FRAMES), None);
+        assert_eq!(state.observe(resized, FRAMES), None);
+        assert_eq!(state.observe(resized, FRAMES), Some(resized));
+    }
+}
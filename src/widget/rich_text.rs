@@ -0,0 +1,374 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Text composed of runs which may each have their own font, size, and color.
+
+use crate::{Element, Renderer};
+use derive_setters::Setters;
+use iced_core::text::{LineHeight, Paragraph, Renderer as TextRenderer, Shaping, Text};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    alignment, layout, mouse, renderer, Color, Font, Layout, Length, Pixels, Point, Rectangle,
+    Size, Widget,
+};
+use std::borrow::Cow;
+
+/// Offset of the baseline below the vertical center of a line box, relative to the font size.
+///
+/// iced does not expose font metrics, so this is derived from the ascender and descender of
+/// Fira Sans. Glyphs are centered in their line box by their ascent and descent, which places
+/// the baseline `(ascent - descent) / 2` below the center.
+const BASELINE_OFFSET: f32 = 0.335;
+
+/// Scale of the font size of superscript and subscript runs.
+const SCRIPT_SCALE: f32 = 0.7;
+
+/// Shift of superscript runs above the baseline, relative to the font size.
+const SUPERSCRIPT_SHIFT: f32 = 0.35;
+
+/// Shift of subscript runs below the baseline, relative to the font size.
+const SUBSCRIPT_SHIFT: f32 = -0.15;
+
+/// Text composed of runs which may each have their own font, size, and color.
+pub fn rich_text<'a>(runs: Vec<TextRun<'a>>) -> RichText<'a> {
+    RichText::new(runs)
+}
+
+/// A span of text within a [`RichText`].
+#[derive(Clone, Debug, Setters)]
+#[must_use]
+pub struct TextRun<'a> {
+    /// The text of the run.
+    #[setters(skip)]
+    pub text: Cow<'a, str>,
+    /// The font used to shape and draw the run.
+    pub font: Font,
+    /// The font size of the run. Defaults to the renderer's default size.
+    #[setters(strip_option)]
+    pub size: Option<f32>,
+    /// The color of the run. Defaults to the text color inherited from the parent.
+    #[setters(strip_option)]
+    pub color: Option<Color>,
+    /// Scales the font size of the run, such as to shrink superscripts.
+    pub scale: f32,
+    /// Raises the run above the baseline of its line by this fraction of its font size.
+    ///
+    /// Negative values lower the run, such as for subscripts.
+    pub baseline_shift: f32,
+}
+
+impl<'a> TextRun<'a> {
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            text: text.into(),
+            font: crate::font::DEFAULT,
+            size: None,
+            color: None,
+            scale: 1.0,
+            baseline_shift: 0.0,
+        }
+    }
+
+    /// Draws the run raised above the baseline at a smaller size.
+    pub fn superscript(self) -> Self {
+        self.scale(SCRIPT_SCALE).baseline_shift(SUPERSCRIPT_SHIFT)
+    }
+
+    /// Draws the run lowered below the baseline at a smaller size.
+    pub fn subscript(self) -> Self {
+        self.scale(SCRIPT_SCALE).baseline_shift(SUBSCRIPT_SHIFT)
+    }
+
+    /// The font size, line height, and offset of the baseline from the top of the line box.
+    fn metrics(&self, default_size: f32, line_height: LineHeight) -> (f32, f32, f32) {
+        let base_size = self.size.unwrap_or(default_size);
+        let size = base_size * self.scale;
+        let line_height = line_height.to_absolute(Pixels(size)).0;
+        let baseline = base_size.mul_add(
+            self.baseline_shift,
+            line_height.mul_add(0.5, size * BASELINE_OFFSET),
+        );
+
+        (size, line_height, baseline)
+    }
+}
+
+/// Text composed of runs which may each have their own font, size, and color.
+///
+/// Runs flow on wrapped lines, breaking between words, with every run on a line sharing the
+/// same baseline. Lines grow to fit runs shifted above or below it.
+#[derive(Setters)]
+#[must_use]
+pub struct RichText<'a> {
+    #[setters(skip)]
+    runs: Vec<TextRun<'a>>,
+    /// Sets the width of the widget.
+    width: Length,
+    /// Sets the line height of each run, relative to its font size.
+    #[setters(into)]
+    line_height: LineHeight,
+}
+
+impl<'a> RichText<'a> {
+    pub fn new(runs: Vec<TextRun<'a>>) -> Self {
+        Self {
+            runs,
+            width: Length::Shrink,
+            line_height: LineHeight::default(),
+        }
+    }
+
+    /// Appends a run to the end of the text.
+    pub fn push(mut self, run: TextRun<'a>) -> Self {
+        self.runs.push(run);
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for RichText<'a> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+        let default_size = renderer.default_size().0;
+
+        let state = tree.state.downcast_mut::<State>();
+        state.segments.clear();
+
+        let mut nodes = Vec::new();
+        let mut line = Line::default();
+        let mut width = 0.0f32;
+
+        for (index, run) in self.runs.iter().enumerate() {
+            let (size, line_height, baseline) = run.metrics(default_size, self.line_height);
+
+            for (nth, text) in run.text.split('\n').enumerate() {
+                if nth > 0 {
+                    line.flush(&mut nodes, line_height);
+                }
+
+                // Whitespace stays attached to the word before it, so lines break between words.
+                for word in text.split_inclusive(char::is_whitespace) {
+                    let paragraph = crate::Paragraph::with_text(Text {
+                        content: word,
+                        bounds: Size::INFINITY,
+                        size: Pixels(size),
+                        line_height: self.line_height,
+                        font: run.font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: Shaping::Advanced,
+                    });
+
+                    let word = Size::new(paragraph.min_bounds().width, line_height);
+                    line.push_wrapped(&mut nodes, word, baseline, max_width);
+                    width = width.max(line.x);
+                    state.segments.push((index, paragraph));
+                }
+            }
+        }
+
+        line.flush(&mut nodes, 0.0);
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(width, line.y));
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        for ((index, paragraph), layout) in state.segments.iter().zip(layout.children()) {
+            let color = self.runs[*index].color.unwrap_or(style.text_color);
+            renderer.fill_paragraph(paragraph, layout.bounds().position(), color, *viewport);
+        }
+    }
+}
+
+impl<'a, Message: 'a> From<RichText<'a>> for Element<'a, Message> {
+    fn from(rich_text: RichText<'a>) -> Self {
+        Self::new(rich_text)
+    }
+}
+
+/// Shaped words, paired with the index of the run they belong to.
+#[derive(Default)]
+struct State {
+    segments: Vec<(usize, crate::Paragraph)>,
+}
+
+/// Words waiting to be placed once the baseline of their line is known.
+#[derive(Default)]
+struct Line {
+    pending: Vec<(Size, f32, f32)>,
+    x: f32,
+    y: f32,
+}
+
+impl Line {
+    fn push(&mut self, size: Size, baseline: f32) {
+        self.pending.push((size, self.x, baseline));
+        self.x += size.width;
+    }
+
+    /// Appends a word, first starting a new line if it would overflow `max_width`.
+    ///
+    /// A word wider than `max_width` is placed alone on its line.
+    fn push_wrapped(
+        &mut self,
+        nodes: &mut Vec<layout::Node>,
+        size: Size,
+        baseline: f32,
+        max_width: f32,
+    ) {
+        if self.x > 0.0 && self.x + size.width > max_width {
+            self.flush(nodes, size.height);
+        }
+
+        self.push(size, baseline);
+    }
+
+    /// Aligns the pending words on a shared baseline and starts a new line.
+    ///
+    /// Empty lines occupy `min_height`.
+    fn flush(&mut self, nodes: &mut Vec<layout::Node>, min_height: f32) {
+        if self.pending.is_empty() {
+            self.y += min_height;
+            return;
+        }
+
+        let ascent = self
+            .pending
+            .iter()
+            .map(|(_, _, baseline)| *baseline)
+            .fold(0.0, f32::max);
+
+        let descent = self
+            .pending
+            .iter()
+            .map(|(size, _, baseline)| size.height - baseline)
+            .fold(0.0, f32::max);
+
+        for (size, x, baseline) in self.pending.drain(..) {
+            nodes.push(layout::Node::new(size).move_to(Point::new(x, self.y + ascent - baseline)));
+        }
+
+        self.x = 0.0;
+        self.y += ascent + descent;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Line, TextRun, BASELINE_OFFSET, SUBSCRIPT_SHIFT, SUPERSCRIPT_SHIFT};
+    use iced_core::text::LineHeight;
+    use iced_core::{layout, Point, Size};
+
+    fn positions(nodes: &[layout::Node]) -> Vec<Point> {
+        nodes.iter().map(|node| node.bounds().position()).collect()
+    }
+
+    #[test]
+    fn test_flush_aligns_baselines() {
+        let mut nodes = Vec::new();
+        let mut line = Line::default();
+
+        // A small run followed by a large one, with baselines 15 and 30 from their tops.
+        line.push(Size::new(40.0, 20.0), 15.0);
+        line.push(Size::new(60.0, 40.0), 30.0);
+        line.flush(&mut nodes, 0.0);
+
+        assert_eq!(
+            positions(&nodes),
+            [Point::new(0.0, 15.0), Point::new(40.0, 0.0)]
+        );
+
+        // Both baselines sit 30 from the top of the line.
+        assert_eq!(nodes[0].bounds().y + 15.0, nodes[1].bounds().y + 30.0);
+
+        // The line is as tall as the tallest ascent and descent together.
+        assert_eq!(line.y, 40.0);
+    }
+
+    #[test]
+    fn test_wrap_within_run() {
+        let mut nodes = Vec::new();
+        let mut line = Line::default();
+        let word = |width| Size::new(width, 20.0);
+
+        for width in [60.0, 30.0, 30.0, 150.0] {
+            line.push_wrapped(&mut nodes, word(width), 15.0, 100.0);
+        }
+        line.flush(&mut nodes, 0.0);
+
+        // The third word overflows onto a second line, and the fourth is too wide for any
+        // line so it is placed alone on a third.
+        assert_eq!(
+            positions(&nodes),
+            [
+                Point::new(0.0, 0.0),
+                Point::new(60.0, 0.0),
+                Point::new(0.0, 20.0),
+                Point::new(0.0, 40.0),
+            ]
+        );
+        assert_eq!(line.y, 60.0);
+    }
+
+    #[test]
+    fn test_scripts_shift_from_shared_baseline() {
+        let mut nodes = Vec::new();
+        let mut line = Line::default();
+        let line_height = LineHeight::default();
+
+        let runs = [
+            TextRun::new("x"),
+            TextRun::new("2").superscript(),
+            TextRun::new("i").subscript(),
+        ];
+
+        let mut glyph_baselines = Vec::new();
+        for run in &runs {
+            let (size, height, baseline) = run.metrics(20.0, line_height);
+            line.push(Size::new(10.0, height), baseline);
+
+            // Where glyphs of this size are drawn within their own line box.
+            glyph_baselines.push(height.mul_add(0.5, size * BASELINE_OFFSET));
+        }
+        line.flush(&mut nodes, 0.0);
+
+        let drawn: Vec<f32> = nodes
+            .iter()
+            .zip(glyph_baselines)
+            .map(|(node, baseline)| node.bounds().y + baseline)
+            .collect();
+
+        // Shifts are relative to the font size of the surrounding text.
+        assert!((drawn[0] - drawn[1] - 20.0 * SUPERSCRIPT_SHIFT).abs() < 0.001);
+        assert!((drawn[0] - drawn[2] - 20.0 * SUBSCRIPT_SHIFT).abs() < 0.001);
+    }
+}
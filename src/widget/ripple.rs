@@ -0,0 +1,359 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Draw a ripple spreading from where a widget was pressed.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::renderer::Quad;
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, touch, window, Border, Clipboard, Color, Layout, Length,
+    Point, Rectangle, Shadow, Shell, Size, Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Opacity of a ripple as it starts.
+const RIPPLE_ALPHA: f32 = 0.2;
+
+/// Draws a ripple spreading from where its content is pressed.
+pub fn ripple<'a, Message>(content: impl Into<Element<'a, Message>>) -> Ripple<'a, Message> {
+    Ripple::new(content)
+}
+
+/// Draws a ripple spreading from where its content is pressed.
+///
+/// Each press starts a new ripple, which grows from the press until it covers the widget while
+/// fading out. Ripples from rapid presses overlap, and a ripple runs to completion even if the
+/// press is released early. Ripples are clipped to the bounds of the widget, and drawn over the
+/// content in its foreground color.
+#[derive(Setters)]
+#[must_use]
+pub struct Ripple<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    /// Time taken by a ripple to cover the widget and fade out.
+    duration: Duration,
+}
+
+impl<'a, Message> Ripple<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            content: content.into(),
+            duration: Duration::from_millis(400),
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for Ripple<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.start(position, Instant::now());
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                if layout.bounds().contains(position) {
+                    state.start(position, Instant::now());
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                if state.frame(now, self.duration) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+
+            _ => (),
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        use iced_core::Renderer as _;
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.ripples.is_empty() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let color = Color::from(theme.current_container().on);
+
+        renderer.with_layer(bounds, |renderer| {
+            for (origin, radius, progress) in state.circles(bounds, self.duration) {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x: origin.x - radius,
+                            y: origin.y - radius,
+                            width: radius * 2.0,
+                            height: radius * 2.0,
+                        },
+                        border: Border {
+                            radius: radius.into(),
+                            ..Border::default()
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    Color {
+                        a: RIPPLE_ALPHA * (1.0 - progress),
+                        ..color
+                    },
+                );
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<Ripple<'a, Message>> for Element<'a, Message> {
+    fn from(ripple: Ripple<'a, Message>) -> Self {
+        Self::new(ripple)
+    }
+}
+
+struct State {
+    /// The origin and start time of each ripple in progress.
+    ripples: Vec<(Point, Instant)>,
+    /// Time of the last frame.
+    now: Instant,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            ripples: Vec::new(),
+            now: Instant::now(),
+        }
+    }
+}
+
+impl State {
+    /// Starts a ripple from `origin` at `now`.
+    fn start(&mut self, origin: Point, now: Instant) {
+        self.now = now;
+        self.ripples.push((origin, now));
+    }
+
+    /// Advances to the frame at `now`, dropping ripples older than `duration`.
+    ///
+    /// Returns whether any ripples are still in progress.
+    fn frame(&mut self, now: Instant, duration: Duration) -> bool {
+        self.now = now;
+        self.ripples
+            .retain(|(_, started)| now.saturating_duration_since(*started) < duration);
+
+        !self.ripples.is_empty()
+    }
+
+    /// The origin, radius, and progress of each ripple as of the last frame.
+    fn circles(
+        &self,
+        bounds: Rectangle,
+        duration: Duration,
+    ) -> impl Iterator<Item = (Point, f32, f32)> + '_ {
+        let duration = duration.as_secs_f32().max(f32::EPSILON);
+
+        self.ripples.iter().map(move |(origin, started)| {
+            let progress =
+                (self.now.saturating_duration_since(*started).as_secs_f32() / duration).min(1.0);
+
+            // Grow until the ripple reaches the corner furthest from the press.
+            let reach = [
+                bounds.position(),
+                Point::new(bounds.x + bounds.width, bounds.y),
+                Point::new(bounds.x, bounds.y + bounds.height),
+                Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+            ]
+            .into_iter()
+            .map(|corner| origin.distance(corner))
+            .fold(0.0, f32::max);
+
+            (*origin, reach * progress, progress)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+    use iced_core::time::{Duration, Instant};
+    use iced_core::{Point, Rectangle, Size};
+
+    const DURATION: Duration = Duration::from_millis(400);
+
+    #[test]
+    fn test_ripple_grows_from_press() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let press = Point::new(30.0, 20.0);
+        let start = Instant::now();
+
+        let mut state = State::default();
+        state.start(press, start);
+
+        let mut radii = Vec::new();
+        for millis in [0, 100, 300] {
+            assert!(state.frame(start + Duration::from_millis(millis), DURATION));
+
+            let circles: Vec<_> = state.circles(bounds, DURATION).collect();
+            assert_eq!(circles.len(), 1);
+
+            let (origin, radius, _) = circles[0];
+            assert_eq!(origin, press);
+            radii.push(radius);
+        }
+
+        assert_eq!(radii[0], 0.0);
+        assert!(radii[0] < radii[1] && radii[1] < radii[2]);
+
+        // Growing toward the corner furthest from the press.
+        let reach = press.distance(Point::new(100.0, 100.0));
+        assert!((radii[1] - reach / 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_ripple_expires() {
+        let start = Instant::now();
+
+        let mut state = State::default();
+        state.start(Point::ORIGIN, start);
+        state.start(Point::ORIGIN, start + Duration::from_millis(200));
+
+        // The first ripple expires while the second is still in progress.
+        assert!(state.frame(start + DURATION, DURATION));
+        assert_eq!(state.ripples.len(), 1);
+
+        assert!(!state.frame(start + DURATION + Duration::from_millis(200), DURATION));
+        assert!(state.ripples.is_empty());
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A column of sections which reports the section at the top of the viewport.
+
+use crate::{Element, Renderer};
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::layout::flex::{self, Axis};
+use iced_core::widget::{tree, Operation, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, Alignment, Clipboard, Layout, Length, Padding, Rectangle,
+    Shell, Size, Widget,
+};
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+/// A column of sections which reports the section at the top of the viewport.
+///
+/// Place inside of a scrollable, such as an A–Z list with a side index.
+pub fn sectioned<'a, Message>(
+    on_section_change: impl Fn(usize) -> Message + 'a,
+) -> Sectioned<'a, Message> {
+    Sectioned::new(on_section_change)
+}
+
+/// A column of sections which reports the section at the top of the viewport.
+///
+/// Each [`header`](Self::header) starts a new section. Whenever the topmost visible section
+/// changes, `on_section_change` is emitted with its index. When scrolling quickly past several
+/// headers at once, only the section which ends up at the top is reported.
+#[must_use]
+#[derive(Setters)]
+pub struct Sectioned<'a, Message> {
+    #[setters(skip)]
+    children: Vec<Element<'a, Message>>,
+    /// Indices of the children which are section headers.
+    #[setters(skip)]
+    headers: Vec<usize>,
+    #[setters(skip)]
+    on_section_change: Box<dyn Fn(usize) -> Message + 'a>,
+    /// Sets the padding around the widget.
+    padding: Padding,
+    /// Sets the space between each child.
+    spacing: u16,
+    /// Alignment of the children along the cross axis.
+    align_items: Alignment,
+    /// Sets the width of the column.
+    width: Length,
+}
+
+impl<'a, Message> Sectioned<'a, Message> {
+    pub fn new(on_section_change: impl Fn(usize) -> Message + 'a) -> Self {
+        Self {
+            children: Vec::new(),
+            headers: Vec::new(),
+            on_section_change: Box::new(on_section_change),
+            padding: Padding::ZERO,
+            spacing: 0,
+            align_items: Alignment::Start,
+            width: Length::Shrink,
+        }
+    }
+
+    /// Starts a new section with the given header.
+    pub fn header(mut self, header: impl Into<Element<'a, Message>>) -> Self {
+        self.headers.push(self.children.len());
+        self.children.push(header.into());
+        self
+    }
+
+    /// Attach a new element to the current section.
+    pub fn push(mut self, widget: impl Into<Element<'a, Message>>) -> Self {
+        self.children.push(widget.into());
+        self
+    }
+
+    /// The index of the last section whose header starts at or above the top of the viewport.
+    fn topmost_section(&self, layout: Layout<'_>, viewport: &Rectangle) -> Option<usize> {
+        let children: Vec<Layout<'_>> = layout.children().collect();
+
+        let header_tops: Vec<f32> = self
+            .headers
+            .iter()
+            .map_while(|index| children.get(*index).map(|header| header.bounds().y))
+            .collect();
+
+        topmost_section(&header_tops, viewport.y)
+    }
+}
+
+/// The index of the last section whose header, starting at `header_tops`, is at or above `top`.
+fn topmost_section(header_tops: &[f32], top: f32) -> Option<usize> {
+    let above = header_tops.iter().take_while(|y| **y <= top).count();
+
+    // Before the first header is scrolled past, the first section is still at the top.
+    (!header_tops.is_empty()).then(|| above.saturating_sub(1))
+}
+
+impl<'a, Message: 'static + Clone> Widget<Message, crate::Theme, Renderer>
+    for Sectioned<'a, Message>
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(self.children.as_mut_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        flex::resolve(
+            Axis::Vertical,
+            renderer,
+            limits,
+            self.width,
+            Length::Shrink,
+            self.padding,
+            f32::from(self.spacing),
+            self.align_items,
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let section = self.topmost_section(layout, viewport);
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(section) = section.filter(|section| state.section != Some(*section)) {
+            state.section = Some(section);
+            shell.publish((self.on_section_change)(section));
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| c.as_widget().a11y_nodes(c_layout, state, p)),
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_style::core::clipboard::DndDestinationRectangles,
+    ) {
+        for ((e, layout), state) in self
+            .children
+            .iter()
+            .zip(layout.children())
+            .zip(state.children.iter())
+        {
+            e.as_widget()
+                .drag_destinations(state, layout, dnd_rectangles);
+        }
+    }
+}
+
+impl<'a, Message: 'static + Clone> From<Sectioned<'a, Message>> for Element<'a, Message> {
+    fn from(sectioned: Sectioned<'a, Message>) -> Self {
+        Self::new(sectioned)
+    }
+}
+
+/// The section last reported as the topmost.
+#[derive(Default)]
+struct State {
+    section: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::topmost_section;
+
+    #[test]
+    fn test_topmost_section() {
+        let header_tops = [0.0, 100.0, 200.0];
+
+        assert_eq!(topmost_section(&header_tops, 0.0), Some(0));
+        assert_eq!(topmost_section(&header_tops, 50.0), Some(0));
+
+        // Scrolling past the end of the first section reaches the second.
+        assert_eq!(topmost_section(&header_tops, 100.0), Some(1));
+        assert_eq!(topmost_section(&header_tops, 150.0), Some(1));
+        assert_eq!(topmost_section(&header_tops, 500.0), Some(2));
+    }
+
+    #[test]
+    fn test_topmost_section_before_first_header() {
+        assert_eq!(topmost_section(&[20.0, 100.0], 0.0), Some(0));
+        assert_eq!(topmost_section(&[], 0.0), None);
+    }
+
+    #[test]
+    fn test_topmost_section_skips_short_sections() {
+        // Two headers pass the top within one scroll step, which lands on the later section.
+        let header_tops = [0.0, 100.0, 110.0, 300.0];
+
+        assert_eq!(topmost_section(&header_tops, 50.0), Some(0));
+        assert_eq!(topmost_section(&header_tops, 120.0), Some(2));
+    }
+}
@@ -32,6 +32,9 @@ use std::marker::PhantomData;
 use std::mem;
 use std::time::{Duration, Instant};
 
+/// Distance the pointer may travel between a middle press and release for it to count as a click.
+const MIDDLE_CLICK_DISTANCE: f32 = 8.0;
+
 /// A command that focuses a segmented item stored in a widget.
 pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
     Command::widget(operation::focusable::focus(id.0))
@@ -589,6 +592,7 @@ where
             wheel_timestamp: Default::default(),
             dnd_state: Default::default(),
             fingers_pressed: Default::default(),
+            middle_pressed: None,
         })
     }
 
@@ -789,6 +793,14 @@ where
             }
         }
 
+        // A middle release ends the click, whether or not it is over the widget.
+        let middle_pressed =
+            if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Middle)) = event {
+                state.middle_pressed.take()
+            } else {
+                None
+            };
+
         if cursor_position.is_over(bounds) {
             let fingers_pressed = state.fingers_pressed.len();
 
@@ -859,10 +871,18 @@ where
                                     return event::Status::Captured;
                                 }
 
-                                // Emit close message if the tab is middle clicked.
-                                if let Event::Mouse(mouse::Event::ButtonReleased(
+                                // Emit close message if the tab is middle clicked. Pointer
+                                // motion in between is a middle-button scroll, not a click.
+                                if let Event::Mouse(mouse::Event::ButtonPressed(
                                     mouse::Button::Middle,
                                 )) = event
+                                {
+                                    state.middle_pressed =
+                                        cursor_position.position().map(|origin| (key, origin));
+                                    return event::Status::Captured;
+                                }
+
+                                if is_middle_click(middle_pressed, key, cursor_position.position())
                                 {
                                     shell.publish(on_close(key));
                                     return event::Status::Captured;
@@ -1600,6 +1620,8 @@ pub struct LocalState {
     pub dnd_state: crate::widget::dnd_destination::State<Entity>,
     /// Tracks multi-touch events
     fingers_pressed: HashSet<Finger>,
+    /// The tab and cursor position of an ongoing middle click.
+    middle_pressed: Option<(Entity, Point)>,
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -1737,3 +1759,52 @@ fn right_button_released(event: &Event) -> bool {
 fn touch_lifted(event: &Event) -> bool {
     matches!(event, Event::Touch(touch::Event::FingerLifted { .. }))
 }
+
+/// Whether a middle button released over `key` at `position` completes a click on it.
+///
+/// The button must have been `pressed` on the same tab, and the pointer must not have moved so
+/// far in between that the press was a middle-button scroll.
+fn is_middle_click(pressed: Option<(Entity, Point)>, key: Entity, position: Option<Point>) -> bool {
+    pressed
+        .zip(position)
+        .is_some_and(|((pressed, origin), position)| {
+            pressed == key && origin.distance(position) < MIDDLE_CLICK_DISTANCE
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_middle_click, Entity, MIDDLE_CLICK_DISTANCE};
+    use iced_core::Point;
+    use slotmap::SlotMap;
+
+    fn tabs() -> (Entity, Entity) {
+        let mut tabs = SlotMap::<Entity, ()>::with_key();
+        (tabs.insert(()), tabs.insert(()))
+    }
+
+    #[test]
+    fn test_middle_click_closes() {
+        let (tab, _) = tabs();
+        let origin = Point::new(10.0, 10.0);
+
+        assert!(is_middle_click(
+            Some((tab, origin)),
+            tab,
+            Some(Point::new(13.0, 12.0))
+        ));
+    }
+
+    #[test]
+    fn test_middle_drag_does_not_close() {
+        let (tab, other) = tabs();
+        let origin = Point::new(10.0, 10.0);
+        let dragged = Point::new(origin.x + MIDDLE_CLICK_DISTANCE, origin.y);
+
+        assert!(!is_middle_click(Some((tab, origin)), tab, Some(dragged)));
+
+        // Nor does a release on another tab, or one which was never pressed.
+        assert!(!is_middle_click(Some((tab, origin)), other, Some(origin)));
+        assert!(!is_middle_click(None, tab, Some(origin)));
+    }
+}
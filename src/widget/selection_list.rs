@@ -0,0 +1,340 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Select several items with clicks, extending the selection with Shift and Ctrl.
+
+use std::collections::BTreeSet;
+
+use iced_core::event::{self, Event};
+use iced_core::renderer::Quad;
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    keyboard, layout, mouse, overlay, renderer, Border, Clipboard, Color, Layout, Length,
+    Rectangle, Shadow, Shell, Size, Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Opacity of the highlight behind selected items.
+const HIGHLIGHT_ALPHA: f32 = 0.2;
+
+/// Selects the items of its content which are clicked, with Shift and Ctrl modifying the selection.
+pub fn selection_list<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    selected: &BTreeSet<usize>,
+    on_selection_change: impl Fn(BTreeSet<usize>) -> Message + 'a,
+) -> SelectionList<'a, Message> {
+    SelectionList::new(content, selected, on_selection_change)
+}
+
+/// Selects the items of its content which are clicked, with Shift and Ctrl modifying the selection.
+///
+/// The items are the direct children of the content, such as those of a
+/// [`column`](super::column). A click selects only the clicked item, Ctrl+click toggles it, and
+/// Shift+click selects the range from the last item clicked without Shift, or from the first
+/// item if there is none. Ctrl+Shift+click adds that range to the selection. Each change emits
+/// `on_selection_change` with the full selection, and the press is still passed on to the item.
+#[must_use]
+pub struct SelectionList<'a, Message> {
+    content: Element<'a, Message>,
+    selected: BTreeSet<usize>,
+    on_selection_change: Box<dyn Fn(BTreeSet<usize>) -> Message + 'a>,
+}
+
+impl<'a, Message> SelectionList<'a, Message> {
+    pub fn new(
+        content: impl Into<Element<'a, Message>>,
+        selected: &BTreeSet<usize>,
+        on_selection_change: impl Fn(BTreeSet<usize>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            selected: selected.clone(),
+            on_selection_change: Box::new(on_selection_change),
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for SelectionList<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                // The content shares its layout, so its children are the items.
+                let clicked = cursor.position_over(layout.bounds()).and_then(|position| {
+                    layout
+                        .children()
+                        .position(|item| item.bounds().contains(position))
+                });
+
+                if let Some(index) = clicked {
+                    let selected =
+                        select(&self.selected, &mut state.anchor, index, state.modifiers);
+
+                    if selected != self.selected {
+                        shell.publish((self.on_selection_change)(selected));
+                    }
+                }
+            }
+
+            _ => (),
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        use iced_core::Renderer as _;
+
+        let cosmic = theme.cosmic();
+        let accent = Color::from(cosmic.accent_color());
+
+        for (_, item) in layout
+            .children()
+            .enumerate()
+            .filter(|(index, _)| self.selected.contains(index))
+        {
+            renderer.fill_quad(
+                Quad {
+                    bounds: item.bounds(),
+                    border: Border {
+                        radius: cosmic.corner_radii.radius_s.into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color {
+                    a: HIGHLIGHT_ALPHA,
+                    ..accent
+                },
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<SelectionList<'a, Message>> for Element<'a, Message> {
+    fn from(selection_list: SelectionList<'a, Message>) -> Self {
+        Self::new(selection_list)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// The item a Shift+click selects a range from.
+    anchor: Option<usize>,
+    modifiers: keyboard::Modifiers,
+}
+
+/// The selection after clicking `index` with `modifiers` held.
+fn select(
+    selected: &BTreeSet<usize>,
+    anchor: &mut Option<usize>,
+    index: usize,
+    modifiers: keyboard::Modifiers,
+) -> BTreeSet<usize> {
+    if modifiers.shift() {
+        let from = *anchor.get_or_insert(0);
+        let range = from.min(index)..=from.max(index);
+
+        if modifiers.control() {
+            selected.iter().copied().chain(range).collect()
+        } else {
+            range.collect()
+        }
+    } else {
+        *anchor = Some(index);
+
+        if modifiers.control() {
+            let mut selected = selected.clone();
+            if !selected.remove(&index) {
+                selected.insert(index);
+            }
+            selected
+        } else {
+            BTreeSet::from([index])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::select;
+    use iced_core::keyboard::Modifiers;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_click_selects_only_item() {
+        let mut anchor = None;
+
+        let selected = select(&BTreeSet::from([1, 4]), &mut anchor, 2, Modifiers::empty());
+        assert_eq!(selected, BTreeSet::from([2]));
+        assert_eq!(anchor, Some(2));
+    }
+
+    #[test]
+    fn test_ctrl_click_toggles_item() {
+        let mut anchor = None;
+
+        let selected = select(&BTreeSet::from([1, 4]), &mut anchor, 2, Modifiers::CTRL);
+        assert_eq!(selected, BTreeSet::from([1, 2, 4]));
+
+        let selected = select(&selected, &mut anchor, 4, Modifiers::CTRL);
+        assert_eq!(selected, BTreeSet::from([1, 2]));
+        assert_eq!(anchor, Some(4));
+    }
+
+    #[test]
+    fn test_shift_click_selects_range() {
+        // Without an anchor, the range starts from the first item.
+        let mut anchor = None;
+        let selected = select(&BTreeSet::new(), &mut anchor, 3, Modifiers::SHIFT);
+        assert_eq!(selected, BTreeSet::from([0, 1, 2, 3]));
+
+        // The anchor stays put, so the range may be moved to either side of it.
+        let mut anchor = Some(5);
+        let selected = select(&BTreeSet::from([9]), &mut anchor, 7, Modifiers::SHIFT);
+        assert_eq!(selected, BTreeSet::from([5, 6, 7]));
+
+        let selected = select(&selected, &mut anchor, 3, Modifiers::SHIFT);
+        assert_eq!(selected, BTreeSet::from([3, 4, 5]));
+        assert_eq!(anchor, Some(5));
+    }
+
+    #[test]
+    fn test_ctrl_shift_click_adds_range() {
+        let mut anchor = Some(2);
+
+        let selected = select(
+            &BTreeSet::from([0, 8]),
+            &mut anchor,
+            4,
+            Modifiers::CTRL | Modifiers::SHIFT,
+        );
+        assert_eq!(selected, BTreeSet::from([0, 2, 3, 4, 8]));
+    }
+}
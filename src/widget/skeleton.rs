@@ -0,0 +1,251 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A placeholder shaped like content which is still loading.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::renderer::Quad;
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    layout, mouse, renderer, window, Border, Clipboard, Color, Layout, Length, Rectangle, Shadow,
+    Shell, Size, Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Width of the shimmer band which sweeps across the placeholder.
+const SHIMMER_WIDTH: f32 = 96.0;
+
+/// A placeholder of the given size, drawn in place of content which is still loading.
+pub fn skeleton(width: impl Into<Length>, height: impl Into<Length>) -> Skeleton {
+    Skeleton::new(width, height)
+}
+
+/// A placeholder drawn in place of content which is still loading.
+///
+/// The placeholder is a rounded rectangle in the component color of the current layer, with a
+/// translucent band sweeping across it. The band crosses the whole placeholder once per
+/// [`period`](Self::period), however wide it is.
+#[derive(Setters)]
+#[must_use]
+pub struct Skeleton {
+    #[setters(into)]
+    width: Length,
+    #[setters(into)]
+    height: Length,
+    /// Corner radius of the placeholder. Defaults to the small corner radius of the theme.
+    #[setters(strip_option)]
+    radius: Option<f32>,
+    /// Time taken by the shimmer to cross the placeholder.
+    period: Duration,
+    /// Whether the shimmer is animated. Disable to draw a static placeholder.
+    animated: bool,
+}
+
+impl Skeleton {
+    pub fn new(width: impl Into<Length>, height: impl Into<Length>) -> Self {
+        Self {
+            width: width.into(),
+            height: height.into(),
+            radius: None,
+            period: Duration::from_millis(1500),
+            animated: true,
+        }
+    }
+
+    /// Horizontal offset of the shimmer band from the left edge of `bounds`, if it is animated.
+    fn shimmer_offset(&self, bounds: Rectangle, elapsed: Duration) -> Option<f32> {
+        if !self.animated {
+            return None;
+        }
+
+        let period = self.period.as_secs_f32().max(f32::EPSILON);
+        let progress = (elapsed.as_secs_f32() % period) / period;
+        Some(progress.mul_add(bounds.width + SHIMMER_WIDTH, -SHIMMER_WIDTH))
+    }
+}
+
+impl<Message> Widget<Message, crate::Theme, Renderer> for Skeleton {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            if self.animated {
+                let state = tree.state.downcast_mut::<State>();
+                state.elapsed = now.saturating_duration_since(state.started);
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        use iced_core::Renderer as _;
+
+        let bounds = layout.bounds();
+        let cosmic = theme.cosmic();
+        let container = theme.current_container();
+        let radius = self
+            .radius
+            .map_or_else(|| cosmic.corner_radii.radius_s.into(), Into::into);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius,
+                    ..Border::default()
+                },
+                shadow: Shadow::default(),
+            },
+            Color::from(container.component.base),
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        let Some(offset) = self.shimmer_offset(bounds, state.elapsed) else {
+            return;
+        };
+
+        let mut shimmer = Color::from(container.on);
+        shimmer.a = 0.08;
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + offset,
+                        width: SHIMMER_WIDTH,
+                        ..bounds
+                    },
+                    border: Border {
+                        radius,
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                shimmer,
+            );
+        });
+    }
+}
+
+impl<'a, Message: 'a> From<Skeleton> for Element<'a, Message> {
+    fn from(skeleton: Skeleton) -> Self {
+        Self::new(skeleton)
+    }
+}
+
+struct State {
+    started: Instant,
+    /// Time since the shimmer started, as of the last frame.
+    elapsed: Duration,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Skeleton, SHIMMER_WIDTH};
+    use iced_core::time::Duration;
+    use iced_core::{Length, Point, Rectangle, Size};
+
+    fn bounds(width: f32) -> Rectangle {
+        Rectangle::new(Point::ORIGIN, Size::new(width, 20.0))
+    }
+
+    fn skeleton() -> Skeleton {
+        Skeleton::new(Length::Fill, 20.0).period(Duration::from_millis(1500))
+    }
+
+    #[test]
+    fn test_shimmer_advances() {
+        let skeleton = skeleton();
+        let offset = |millis| skeleton.shimmer_offset(bounds(300.0), Duration::from_millis(millis));
+
+        assert_eq!(offset(0), Some(-SHIMMER_WIDTH));
+        assert_eq!(offset(750), Some(102.0));
+
+        let offsets: Vec<_> = (0..5).map(|n| offset(n * 300).unwrap()).collect();
+        assert!(offsets.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // It starts over once per period.
+        assert_eq!(offset(1500), offset(0));
+    }
+
+    #[test]
+    fn test_shimmer_crosses_wide_bounds() {
+        let skeleton = skeleton();
+        let width = 10_000.0;
+
+        // Starting wholly left of the placeholder, and reaching its right edge within a period.
+        let start = skeleton
+            .shimmer_offset(bounds(width), Duration::ZERO)
+            .unwrap();
+        assert!(start + SHIMMER_WIDTH <= 0.0);
+
+        let end = skeleton
+            .shimmer_offset(bounds(width), Duration::from_millis(1499))
+            .unwrap();
+        assert!(end + SHIMMER_WIDTH > width);
+    }
+
+    #[test]
+    fn test_shimmer_static() {
+        let skeleton = skeleton().animated(false);
+
+        for millis in [0, 750, 1499] {
+            assert_eq!(
+                skeleton.shimmer_offset(bounds(300.0), Duration::from_millis(millis)),
+                None
+            );
+        }
+    }
+}
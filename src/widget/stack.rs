@@ -0,0 +1,270 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A container which places its children on top of each other.
+
+use crate::{Element, Renderer};
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::widget::{Operation, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, Clipboard, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+/// A container which places its children on top of each other.
+pub fn stack<'a, Message>() -> Stack<'a, Message> {
+    Stack::new()
+}
+
+/// A container which places its children on top of each other.
+///
+/// Every child is laid out against the full limits of the stack, at its top left, and each
+/// child pushed is drawn above those pushed before it. Events reach the topmost child first;
+/// once a child captures an event, the children below it no longer see the cursor.
+///
+/// By default, each child is drawn one theme layer deeper than the child below it, so that an
+/// overlapping card or badge takes the colors of the layer above its background.
+#[must_use]
+#[derive(Setters)]
+pub struct Stack<'a, Message> {
+    #[setters(skip)]
+    children: Vec<Element<'a, Message>>,
+    /// Draws each child one theme layer deeper than the child below it.
+    layered: bool,
+    /// Sets the width of the container.
+    width: Length,
+    /// Sets the height of the container.
+    height: Length,
+}
+
+impl<'a, Message> Default for Stack<'a, Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message> Stack<'a, Message> {
+    pub const fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            layered: true,
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Attach a new element on top of the others.
+    pub fn push(mut self, widget: impl Into<Element<'a, Message>>) -> Self {
+        self.children.push(widget.into());
+        self
+    }
+}
+
+impl<'a, Message: 'static + Clone> Widget<Message, crate::Theme, Renderer> for Stack<'a, Message> {
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(self.children.as_mut_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let nodes: Vec<layout::Node> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| child.as_widget().layout(tree, renderer, &limits))
+            .collect();
+
+        let content = nodes.iter().fold(Size::ZERO, |content, node| {
+            let size = node.size();
+            Size::new(
+                content.width.max(size.width),
+                content.height.max(size.height),
+            )
+        });
+
+        let size = limits.resolve(self.width, self.height, content);
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+        let mut status = event::Status::Ignored;
+
+        // The topmost child is given the event first.
+        for ((child, state), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layouts)
+            .rev()
+        {
+            let cursor = if status == event::Status::Captured {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            };
+
+            status = status.merge(child.as_widget_mut().on_event(
+                state,
+                event.clone(),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layouts)
+            .rev()
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .find(|interaction| *interaction != mouse::Interaction::Idle)
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut theme = theme.clone();
+
+        for (index, ((child, state), layout)) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            if self.layered && index > 0 {
+                theme.layer = theme.layer.child_layer();
+            }
+
+            child
+                .as_widget()
+                .draw(state, renderer, &theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| c.as_widget().a11y_nodes(c_layout, state, p)),
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_style::core::clipboard::DndDestinationRectangles,
+    ) {
+        for ((e, layout), state) in self
+            .children
+            .iter()
+            .zip(layout.children())
+            .zip(state.children.iter())
+        {
+            e.as_widget()
+                .drag_destinations(state, layout, dnd_rectangles);
+        }
+    }
+}
+
+impl<'a, Message: 'static + Clone> From<Stack<'a, Message>> for Element<'a, Message> {
+    fn from(stack: Stack<'a, Message>) -> Self {
+        Self::new(stack)
+    }
+}
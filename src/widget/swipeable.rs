@@ -0,0 +1,424 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Detect swipe gestures on a widget.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::time::Instant;
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, touch, Clipboard, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Distance the pointer must travel before a gesture is locked to an axis.
+const LOCK_DISTANCE: f32 = 8.0;
+
+/// Emits a message when its content is swiped.
+pub fn swipeable<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    on_swipe: impl Fn(Direction) -> Message + 'a,
+) -> Swipeable<'a, Message> {
+    Swipeable::new(content, on_swipe)
+}
+
+/// The direction of a swipe gesture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Emits a message when its content is swiped.
+///
+/// A gesture locks to the horizontal or vertical axis once the pointer has moved a few pixels,
+/// so a swipe along one axis is never mistaken for one along the other. On release, the swipe
+/// is emitted if it traveled far enough or fast enough along that axis; otherwise it is
+/// discarded.
+#[derive(Setters)]
+#[must_use]
+pub struct Swipeable<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    #[setters(skip)]
+    on_swipe: Box<dyn Fn(Direction) -> Message + 'a>,
+    /// Distance along the locked axis which completes a swipe.
+    distance: f32,
+    /// Speed along the locked axis, in pixels per second, which completes a swipe.
+    velocity: f32,
+}
+
+impl<'a, Message> Swipeable<'a, Message> {
+    pub fn new(
+        content: impl Into<Element<'a, Message>>,
+        on_swipe: impl Fn(Direction) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_swipe: Box::new(on_swipe),
+            distance: 64.0,
+            velocity: 600.0,
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for Swipeable<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let released = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.gesture = Some(Gesture::new(position, None, Instant::now()));
+                }
+                None
+            }
+
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if state.gesture.is_none() && layout.bounds().contains(position) {
+                    state.gesture = Some(Gesture::new(position, Some(id), Instant::now()));
+                }
+                None
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(gesture) = state.gesture.as_mut().filter(|g| g.finger.is_none()) {
+                    gesture.update(position);
+                }
+                None
+            }
+
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let Some(gesture) = state.gesture.as_mut().filter(|g| g.finger == Some(id)) {
+                    gesture.update(position);
+                }
+                None
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => state.end(None),
+
+            Event::Touch(touch::Event::FingerLifted { id, position }) => {
+                state.end(Some(id)).map(|mut gesture| {
+                    gesture.update(position);
+                    gesture
+                })
+            }
+
+            Event::Touch(touch::Event::FingerLost { id, .. }) => {
+                _ = state.end(Some(id));
+                None
+            }
+
+            _ => None,
+        };
+
+        if let Some(direction) = released
+            .and_then(|gesture| gesture.direction(Instant::now(), self.distance, self.velocity))
+        {
+            shell.publish((self.on_swipe)(direction));
+            return event::Status::Captured;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<Swipeable<'a, Message>> for Element<'a, Message> {
+    fn from(swipeable: Swipeable<'a, Message>) -> Self {
+        Self::new(swipeable)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    gesture: Option<Gesture>,
+}
+
+impl State {
+    /// Ends the gesture if it is being performed by the given finger, or the mouse for `None`.
+    fn end(&mut self, finger: Option<touch::Finger>) -> Option<Gesture> {
+        if self.gesture.is_some_and(|gesture| gesture.finger == finger) {
+            self.gesture.take()
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+/// A press which may become a swipe.
+#[derive(Clone, Copy)]
+struct Gesture {
+    origin: Point,
+    current: Point,
+    started: Instant,
+    axis: Option<Axis>,
+    /// The finger performing the gesture, or `None` for the mouse.
+    finger: Option<touch::Finger>,
+}
+
+impl Gesture {
+    fn new(origin: Point, finger: Option<touch::Finger>, started: Instant) -> Self {
+        Self {
+            origin,
+            current: origin,
+            started,
+            axis: None,
+            finger,
+        }
+    }
+
+    fn update(&mut self, position: Point) {
+        self.current = position;
+
+        if self.axis.is_none() {
+            let dx = (position.x - self.origin.x).abs();
+            let dy = (position.y - self.origin.y).abs();
+
+            if dx.hypot(dy) >= LOCK_DISTANCE {
+                self.axis = Some(if dx >= dy {
+                    Axis::Horizontal
+                } else {
+                    Axis::Vertical
+                });
+            }
+        }
+    }
+
+    /// The direction of the swipe, if the gesture completed one by `now`.
+    fn direction(&self, now: Instant, distance: f32, velocity: f32) -> Option<Direction> {
+        let (delta, negative, positive) = match self.axis? {
+            Axis::Horizontal => (
+                self.current.x - self.origin.x,
+                Direction::Left,
+                Direction::Right,
+            ),
+            Axis::Vertical => (
+                self.current.y - self.origin.y,
+                Direction::Up,
+                Direction::Down,
+            ),
+        };
+
+        let elapsed = now
+            .saturating_duration_since(self.started)
+            .as_secs_f32()
+            .max(f32::EPSILON);
+
+        if delta.abs() < distance && delta.abs() / elapsed < velocity {
+            return None;
+        }
+
+        Some(if delta < 0.0 { negative } else { positive })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Gesture};
+    use iced_core::time::{Duration, Instant};
+    use iced_core::Point;
+
+    const DISTANCE: f32 = 64.0;
+    const VELOCITY: f32 = 600.0;
+
+    /// A gesture from `origin` through each of `path`, released `elapsed` after it started.
+    fn swipe(origin: Point, path: &[Point], elapsed: Duration) -> Option<Direction> {
+        let started = Instant::now();
+        let mut gesture = Gesture::new(origin, None, started);
+
+        for position in path {
+            gesture.update(*position);
+        }
+
+        gesture.direction(started + elapsed, DISTANCE, VELOCITY)
+    }
+
+    #[test]
+    fn test_swipe_left() {
+        let direction = swipe(
+            Point::new(200.0, 100.0),
+            &[Point::new(150.0, 104.0), Point::new(100.0, 108.0)],
+            Duration::from_secs(1),
+        );
+
+        assert_eq!(direction, Some(Direction::Left));
+    }
+
+    #[test]
+    fn test_axis_lock() {
+        // Locked horizontal by the first move, so the later vertical travel is ignored.
+        let direction = swipe(
+            Point::new(0.0, 0.0),
+            &[Point::new(10.0, 2.0), Point::new(20.0, 200.0)],
+            Duration::from_secs(1),
+        );
+
+        assert_eq!(direction, None);
+
+        // Movement within the lock distance never picks an axis.
+        let direction = swipe(
+            Point::new(0.0, 0.0),
+            &[Point::new(4.0, 4.0)],
+            Duration::from_millis(1),
+        );
+
+        assert_eq!(direction, None);
+    }
+
+    #[test]
+    fn test_swipe_velocity() {
+        // Short of the distance, but fast enough.
+        let direction = swipe(
+            Point::new(0.0, 0.0),
+            &[Point::new(0.0, 30.0)],
+            Duration::from_millis(20),
+        );
+
+        assert_eq!(direction, Some(Direction::Down));
+    }
+
+    #[test]
+    fn test_snap_back() {
+        // Short of the distance, and too slow.
+        let direction = swipe(
+            Point::new(0.0, 0.0),
+            &[Point::new(30.0, 0.0)],
+            Duration::from_secs(1),
+        );
+
+        assert_eq!(direction, None);
+    }
+}
@@ -2,6 +2,7 @@ use crate::Renderer;
 pub use iced::widget::Text;
 use iced_core::text::LineHeight;
 use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Creates a new [`Text`] widget with the provided content.
 ///
@@ -90,3 +91,78 @@ pub fn monotext<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Text<'a, crate::Theme
         .line_height(LineHeight::Absolute(20.0.into()))
         .font(crate::font::FONT_MONO_REGULAR)
 }
+
+/// Expands tabs in `text` to spaces, up to the next tab stop every `tab_width` columns.
+///
+/// Intended for monospaced text such as [`monotext`], where each grapheme occupies one column.
+/// Column counting restarts on every line, and the text is borrowed if it contains no tabs.
+/// A `tab_width` of zero is treated as one, so that each tab becomes a single space.
+#[must_use]
+pub fn expand_tabs(text: &str, tab_width: usize) -> Cow<'_, str> {
+    if !text.contains('\t') {
+        return Cow::Borrowed(text);
+    }
+
+    let tab_width = tab_width.max(1);
+    let mut expanded = String::with_capacity(text.len());
+    let mut column = 0;
+
+    for grapheme in text.graphemes(true) {
+        match grapheme {
+            "\t" => {
+                let spaces = tab_width - column % tab_width;
+                expanded.extend(std::iter::repeat(' ').take(spaces));
+                column += spaces;
+            }
+
+            "\n" | "\r\n" => {
+                expanded.push_str(grapheme);
+                column = 0;
+            }
+
+            _ => {
+                expanded.push_str(grapheme);
+                column += 1;
+            }
+        }
+    }
+
+    Cow::Owned(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::expand_tabs;
+
+    #[test]
+    fn test_expand_tabs_mid_line() {
+        assert_eq!(expand_tabs("\tx", 4), "    x");
+        assert_eq!(expand_tabs("ab\tx", 4), "ab  x");
+        assert_eq!(expand_tabs("abcd\tx", 4), "abcd    x");
+        // Columns restart after each line break.
+        assert_eq!(expand_tabs("abc\n\tx\r\na\tx", 4), "abc\n    x\r\na   x");
+    }
+
+    #[test]
+    fn test_expand_tabs_multiple() {
+        assert_eq!(expand_tabs("\t\tx", 4), "        x");
+        assert_eq!(expand_tabs("a\tbc\td", 4), "a   bc  d");
+        // A grapheme with a combining mark takes a single column.
+        assert_eq!(expand_tabs("e\u{301}\tx", 4), "e\u{301}   x");
+    }
+
+    #[test]
+    fn test_expand_tabs_zero_width() {
+        assert_eq!(expand_tabs("a\t\tb", 0), "a  b");
+    }
+
+    #[test]
+    fn test_expand_tabs_borrows_without_tabs() {
+        assert!(matches!(
+            expand_tabs("no tabs", 4),
+            Cow::Borrowed("no tabs")
+        ));
+    }
+}
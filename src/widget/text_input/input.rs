@@ -62,6 +62,10 @@ where
 }
 
 /// A text label whiich can transform into a text input on activation.
+///
+/// Editing starts when the label is clicked, or when F2 is pressed while it is focused. Enter
+/// submits the edit and ends it. Escape cancels it, restoring the value from before the edit
+/// began with `on_input`, and emits `on_toggle_edit(false)`.
 pub fn editable_input<'a, Message: Clone + 'static>(
     placeholder: impl Into<Cow<'a, str>>,
     text: impl Into<Cow<'a, str>>,
@@ -206,6 +210,7 @@ pub struct TextInput<'a, Message> {
     dnd_icon: bool,
     line_height: text::LineHeight,
     helper_line_height: text::LineHeight,
+    cursor_blink: Option<Duration>,
 }
 
 impl<'a, Message> TextInput<'a, Message>
@@ -249,6 +254,7 @@ where
             line_height: text::LineHeight::default(),
             label: None,
             helper_text: None,
+            cursor_blink: Some(CURSOR_BLINK_INTERVAL),
         }
     }
 
@@ -271,6 +277,9 @@ where
     }
 
     /// Sets the error message of the [`TextInput`].
+    ///
+    /// The message is shown in place of the helper text, and the input is outlined in the
+    /// destructive color, until the error is cleared.
     pub fn error(mut self, error: &'a str) -> Self {
         self.error = Some(error);
         self
@@ -382,6 +391,15 @@ where
         self
     }
 
+    /// Sets the interval at which the cursor of the [`TextInput`] blinks.
+    ///
+    /// The cursor stays visible while typing. `None` keeps it solid, for users
+    /// who prefer reduced motion.
+    pub fn cursor_blink(mut self, interval: Option<Duration>) -> Self {
+        self.cursor_blink = interval.filter(|interval| interval.as_millis() > 0);
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     pub fn style(mut self, style: impl Into<<crate::Theme as StyleSheet>::Style>) -> Self {
         self.style = style.into();
@@ -422,9 +440,10 @@ where
             self.line_height,
             self.error,
             self.label,
-            self.helper_text,
+            helper_text(self.error, self.helper_text),
             self.helper_size,
             self.helper_line_height,
+            self.cursor_blink,
             &layout.bounds(),
             style,
         );
@@ -542,7 +561,7 @@ where
                 .iter()
                 .map(|l| l.text())
                 .collect::<String>()
-                != self.helper_text.unwrap_or_default()
+                != helper_text(self.error, self.helper_text).unwrap_or_default()
         {
             state.is_secure = self.is_secure;
             state.dirty = true;
@@ -614,7 +633,7 @@ where
             let size = limits.resolve(width, height, Size::new(width, height));
             layout::Node::with_children(size, vec![layout::Node::new(size)])
         } else {
-            let res = layout(
+            let res = layout_with_error(
                 renderer,
                 limits,
                 self.width,
@@ -624,10 +643,11 @@ where
                 self.trailing_icon.as_ref(),
                 self.line_height,
                 self.label,
-                self.helper_text,
+                helper_text(self.error, self.helper_text),
                 self.helper_size,
                 self.helper_line_height,
                 font,
+                self.error.is_some(),
                 tree,
             );
 
@@ -724,6 +744,7 @@ where
                 let state = tree.state.downcast_mut::<State>();
                 if !state.is_read_only && state.is_focused.is_none() {
                     state.is_read_only = true;
+                    state.editing_from = None;
                     shell.publish((on_edit)(false));
                 }
             }
@@ -780,6 +801,7 @@ where
             self.on_dnd_command_produced.as_deref(),
             self.surface_ids,
             line_height,
+            self.cursor_blink,
             layout,
         )
     }
@@ -813,9 +835,10 @@ where
             self.line_height,
             self.error,
             self.label,
-            self.helper_text,
+            helper_text(self.error, self.helper_text),
             self.helper_size,
             self.helper_line_height,
+            self.cursor_blink,
             viewport,
             style,
         );
@@ -918,10 +941,50 @@ pub fn select_all<Message: 'static>(id: Id) -> Command<Message> {
 }
 
 /// Computes the layout of a [`TextInput`].
+#[allow(clippy::too_many_arguments)]
+pub fn layout<Message>(
+    renderer: &crate::Renderer,
+    limits: &layout::Limits,
+    width: Length,
+    padding: Padding,
+    size: Option<f32>,
+    leading_icon: Option<&Element<'_, Message, crate::Theme, crate::Renderer>>,
+    trailing_icon: Option<&Element<'_, Message, crate::Theme, crate::Renderer>>,
+    line_height: text::LineHeight,
+    label: Option<&str>,
+    helper_text: Option<&str>,
+    helper_text_size: f32,
+    helper_text_line_height: text::LineHeight,
+    font: iced_core::Font,
+    tree: &mut Tree,
+) -> layout::Node {
+    layout_with_error(
+        renderer,
+        limits,
+        width,
+        padding,
+        size,
+        leading_icon,
+        trailing_icon,
+        line_height,
+        label,
+        helper_text,
+        helper_text_size,
+        helper_text_line_height,
+        font,
+        false,
+        tree,
+    )
+}
+
+/// Computes the layout of a [`TextInput`], where `is_error` marks the helper text as an error.
+///
+/// Helper text is clamped to a single line, while an error message wraps onto as many lines
+/// as it needs.
 #[allow(clippy::cast_precision_loss)]
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::too_many_lines)]
-pub fn layout<Message>(
+fn layout_with_error<Message>(
     renderer: &crate::Renderer,
     limits: &layout::Limits,
     width: Length,
@@ -935,6 +998,7 @@ pub fn layout<Message>(
     helper_text_size: f32,
     helper_text_line_height: text::LineHeight,
     font: iced_core::Font,
+    is_error: bool,
     tree: &mut Tree,
 ) -> layout::Node {
     let limits = limits.width(width);
@@ -1076,10 +1140,10 @@ pub fn layout<Message>(
     };
 
     if let Some(helper_text) = helper_text {
-        let limits = limits
-            .width(width)
-            .shrink(padding)
-            .height(helper_text_line_height.to_absolute(helper_text_size.into()));
+        let mut limits = limits.width(width).shrink(padding);
+        if !is_error {
+            limits = limits.height(helper_text_line_height.to_absolute(helper_text_size.into()));
+        }
         let text_bounds = limits.resolve(width, Length::Shrink, Size::INFINITY);
         let state = tree.state.downcast_mut::<State>();
         let helper_text_paragraph = &mut state.helper_text;
@@ -1143,6 +1207,7 @@ pub fn update<'a, Message>(
     #[allow(unused_variables)] on_dnd_command_produced: Option<&dyn Fn(DnDCommand) -> Message>,
     #[allow(unused_variables)] surface_ids: Option<(window::Id, window::Id)>,
     line_height: text::LineHeight,
+    cursor_blink: Option<Duration>,
     layout: Layout<'_>,
 ) -> event::Status
 where
@@ -1337,6 +1402,7 @@ where
                     && matches!(state.dragging_state, None | Some(DraggingState::Selection))
                 {
                     state.is_read_only = false;
+                    state.editing_from = Some(unsecured_value.to_string());
                     if let Some(on_toggle_edit) = on_toggle_edit {
                         let message = (on_toggle_edit)(!state.is_read_only);
                         shell.publish(message);
@@ -1375,6 +1441,8 @@ where
                             state.cursor().state(value),
                         ) {
                             (None, click::Kind::Single, _) => {
+                                state.editing_from =
+                                    state.is_read_only.then(|| unsecured_value.to_string());
                                 state.is_read_only = !state.is_read_only;
                                 if let Some(on_toggle_edit) = on_toggle_edit {
                                     let message = (on_toggle_edit)(!state.is_read_only);
@@ -1430,6 +1498,22 @@ where
                     return event::Status::Ignored;
                 };
 
+                // F2 starts editing a focused editable input label.
+                if is_editable
+                    && state.is_read_only
+                    && key == keyboard::Key::Named(keyboard::key::Named::F2)
+                {
+                    state.is_read_only = false;
+                    state.editing_from = Some(unsecured_value.to_string());
+                    state.move_cursor_to_end();
+
+                    if let Some(on_toggle_edit) = on_toggle_edit {
+                        shell.publish((on_toggle_edit)(true));
+                    }
+
+                    return event::Status::Captured;
+                }
+
                 if state.is_read_only {
                     return event::Status::Ignored;
                 }
@@ -1442,6 +1526,15 @@ where
                         if let Some(on_submit) = on_submit.clone() {
                             shell.publish(on_submit);
                         }
+
+                        // Enter ends editing of an editable input label.
+                        if is_editable {
+                            state.is_read_only = true;
+                            state.editing_from = None;
+                            if let Some(on_toggle_edit) = on_toggle_edit {
+                                shell.publish((on_toggle_edit)(false));
+                            }
+                        }
                     }
                     keyboard::Key::Named(keyboard::key::Named::Backspace) => {
                         if platform::is_jump_modifier_pressed(modifiers)
@@ -1615,6 +1708,22 @@ where
                         state.is_pasting = None;
 
                         state.keyboard_modifiers = keyboard::Modifiers::default();
+
+                        // Escape cancels editing of an editable input label, restoring the
+                        // value from before the edit.
+                        if is_editable {
+                            state.is_read_only = true;
+
+                            if let Some(original) = state.editing_from.take() {
+                                if original != unsecured_value.to_string() {
+                                    shell.publish((on_input)(original));
+                                }
+                            }
+
+                            if let Some(on_toggle_edit) = on_toggle_edit {
+                                shell.publish((on_toggle_edit)(false));
+                            }
+                        }
                     }
                     keyboard::Key::Named(
                         keyboard::key::Named::Tab
@@ -1687,12 +1796,15 @@ where
             if let Some(focus) = &mut state.is_focused {
                 focus.now = now;
 
-                let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
-                    - (now - focus.updated_at).as_millis() % CURSOR_BLINK_INTERVAL_MILLIS;
+                if let Some(interval) = cursor_blink {
+                    let interval = interval.as_millis();
+                    let millis_until_redraw =
+                        interval - (now - focus.updated_at).as_millis() % interval;
 
-                shell.request_redraw(window::RedrawRequest::At(
-                    now + Duration::from_millis(u64::try_from(millis_until_redraw).unwrap()),
-                ));
+                    shell.request_redraw(window::RedrawRequest::At(
+                        now + Duration::from_millis(u64::try_from(millis_until_redraw).unwrap()),
+                    ));
+                }
             }
         }
         #[cfg(feature = "wayland")]
@@ -1971,6 +2083,7 @@ pub fn draw<'a, Message>(
     helper_text: Option<&str>,
     helper_text_size: f32,
     helper_line_height: text::LineHeight,
+    cursor_blink: Option<Duration>,
     viewport: &Rectangle,
     renderer_style: &renderer::Style,
 ) {
@@ -2141,9 +2254,7 @@ pub fn draw<'a, Message>(
                 let (text_value_width, offset) =
                     measure_cursor_and_scroll_offset(&state.value, text_bounds, position);
 
-                let is_cursor_visible =
-                    ((focus.now - focus.updated_at).as_millis() / CURSOR_BLINK_INTERVAL_MILLIS) % 2
-                        == 0;
+                let is_cursor_visible = focus.is_cursor_visible(cursor_blink);
 
                 if is_cursor_visible {
                     if dnd_icon {
@@ -2290,8 +2401,10 @@ pub fn draw<'a, Message>(
         );
     }
 
-    // draw the helper text if it exists
+    // draw the helper text if it exists, which is the error message when there is an error
     if let (Some(helper_text_layout), Some(helper_text)) = (helper_text_layout, helper_text) {
+        let helper_text_color = helper_text_color(theme, error.is_some(), text_color);
+
         renderer.fill_text(
             Text {
                 content: helper_text,
@@ -2304,7 +2417,7 @@ pub fn draw<'a, Message>(
                 shaping: text::Shaping::Advanced,
             },
             helper_text_layout.bounds().position(),
-            text_color,
+            helper_text_color,
             *viewport,
         );
     }
@@ -2380,6 +2493,9 @@ pub struct State {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    /// The value before the edit in progress of an editable input label, restored if the edit
+    /// is cancelled.
+    editing_from: Option<String>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -2389,6 +2505,15 @@ struct Focus {
     now: Instant,
 }
 
+impl Focus {
+    /// Whether the cursor is in the visible phase of its blink.
+    fn is_cursor_visible(&self, blink: Option<Duration>) -> bool {
+        blink.map_or(true, |interval| {
+            ((self.now - self.updated_at).as_millis() / interval.as_millis()) % 2 == 0
+        })
+    }
+}
+
 impl State {
     /// Creates a new [`State`], representing an unfocused [`TextInput`].
     pub fn new(is_secure: bool, is_read_only: bool) -> Self {
@@ -2588,7 +2713,21 @@ fn replace_paragraph(
     });
 }
 
-const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
+const CURSOR_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The text shown below the input, which is the error message when there is one.
+fn helper_text<'a>(error: Option<&'a str>, helper_text: Option<&'a str>) -> Option<&'a str> {
+    error.or(helper_text)
+}
+
+/// The color of the text below the input, which is destructive for an error message.
+fn helper_text_color(theme: &crate::Theme, is_error: bool, text_color: Color) -> Color {
+    if is_error {
+        theme.cosmic().destructive_text_color().into()
+    } else {
+        text_color
+    }
+}
 
 mod platform {
     use iced_core::keyboard;
@@ -2619,3 +2758,212 @@ fn offset(text_bounds: Rectangle, value: &Value, state: &State) -> f32 {
         0.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_core::keyboard::{self, key::Named};
+    use iced_core::{clipboard, layout, mouse, text, Color, Event, Layout, Shell, Size};
+
+    use super::{helper_text, helper_text_color, update, Focus, State, Value};
+    use iced_core::time::{Duration, Instant};
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Message {
+        Input(String),
+        Submit,
+        ToggleEdit(bool),
+    }
+
+    /// Presses `key` on an editable input label holding `value`, returning the messages emitted.
+    fn press(
+        state: &mut State,
+        value: &str,
+        key: keyboard::Key,
+        text: Option<&str>,
+    ) -> Vec<Message> {
+        let size = Size::new(200.0, 32.0);
+        let node = layout::Node::with_children(size, vec![layout::Node::new(size)]);
+        let layout = Layout::new(&node);
+
+        let on_input = Message::Input;
+        let on_toggle_edit = Message::ToggleEdit;
+        let mut messages = Vec::new();
+
+        let _ = update(
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+                text: text.map(Into::into),
+            }),
+            layout,
+            None,
+            mouse::Cursor::Unavailable,
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &mut Value::new(value),
+            14.0,
+            crate::font::DEFAULT,
+            false,
+            true,
+            Some(&on_input),
+            None,
+            &Some(Message::Submit),
+            Some(&on_toggle_edit),
+            || state,
+            None,
+            false,
+            None,
+            None,
+            text::LineHeight::default(),
+            None,
+            layout,
+        );
+
+        messages
+    }
+
+    fn character(c: &str) -> keyboard::Key {
+        keyboard::Key::Character(c.into())
+    }
+
+    #[test]
+    fn test_edit_label_from_keyboard() {
+        let mut state = State::new(false, true);
+        state.focus();
+
+        // Typing is ignored until editing starts.
+        assert_eq!(
+            press(&mut state, "notes", character("s"), Some("s")),
+            vec![]
+        );
+
+        assert_eq!(
+            press(&mut state, "notes", keyboard::Key::Named(Named::F2), None),
+            vec![Message::ToggleEdit(true)]
+        );
+        assert!(!state.is_read_only);
+
+        assert_eq!(
+            press(&mut state, "notes", character("2"), Some("2")),
+            vec![Message::Input(String::from("notes2"))]
+        );
+
+        assert_eq!(
+            press(
+                &mut state,
+                "notes2",
+                keyboard::Key::Named(Named::Enter),
+                None
+            ),
+            vec![Message::Submit, Message::ToggleEdit(false)]
+        );
+        assert!(state.is_read_only);
+    }
+
+    #[test]
+    fn test_escape_restores_label() {
+        let mut state = State::new(false, true);
+        state.focus();
+
+        press(&mut state, "notes", keyboard::Key::Named(Named::F2), None);
+        press(&mut state, "notes", character("2"), Some("2"));
+
+        assert_eq!(
+            press(
+                &mut state,
+                "notes2",
+                keyboard::Key::Named(Named::Escape),
+                None
+            ),
+            vec![
+                Message::Input(String::from("notes")),
+                Message::ToggleEdit(false)
+            ]
+        );
+        assert!(state.is_read_only);
+    }
+
+    #[test]
+    fn test_escape_without_changes() {
+        let mut state = State::new(false, true);
+        state.focus();
+
+        press(&mut state, "notes", keyboard::Key::Named(Named::F2), None);
+
+        assert_eq!(
+            press(
+                &mut state,
+                "notes",
+                keyboard::Key::Named(Named::Escape),
+                None
+            ),
+            vec![Message::ToggleEdit(false)]
+        );
+    }
+
+    #[test]
+    fn test_error_replaces_helper_text() {
+        assert_eq!(helper_text(None, Some("helper")), Some("helper"));
+        assert_eq!(helper_text(Some("error"), Some("helper")), Some("error"));
+        assert_eq!(helper_text(Some("error"), None), Some("error"));
+        assert_eq!(helper_text(None, None), None);
+    }
+
+    #[test]
+    fn test_error_uses_destructive_text_color() {
+        let theme = crate::Theme::dark();
+        let text_color = Color::from_rgb(0.5, 0.5, 0.5);
+
+        assert_eq!(
+            helper_text_color(&theme, true, text_color),
+            Color::from(theme.cosmic().destructive_text_color())
+        );
+        assert_eq!(helper_text_color(&theme, false, text_color), text_color);
+    }
+
+    #[test]
+    fn test_cursor_blinks_at_interval() {
+        let blink = Some(Duration::from_millis(500));
+        let start = Instant::now();
+        let focus = |millis| Focus {
+            updated_at: start,
+            now: start + Duration::from_millis(millis),
+        };
+
+        assert!(focus(0).is_cursor_visible(blink));
+        assert!(focus(499).is_cursor_visible(blink));
+        assert!(!focus(500).is_cursor_visible(blink));
+        assert!(!focus(999).is_cursor_visible(blink));
+        assert!(focus(1000).is_cursor_visible(blink));
+    }
+
+    #[test]
+    fn test_update_resets_blink_phase() {
+        let blink = Some(Duration::from_millis(500));
+        let start = Instant::now();
+        let mut focus = Focus {
+            updated_at: start,
+            now: start + Duration::from_millis(700),
+        };
+        assert!(!focus.is_cursor_visible(blink));
+
+        // Typing moves the start of the blink, so the cursor shows again right away.
+        focus.updated_at = focus.now;
+        assert!(focus.is_cursor_visible(blink));
+    }
+
+    #[test]
+    fn test_cursor_solid_without_blink() {
+        let start = Instant::now();
+
+        for millis in [0, 500, 750, 1500] {
+            let focus = Focus {
+                updated_at: start,
+                now: start + Duration::from_millis(millis),
+            };
+
+            assert!(focus.is_cursor_visible(None));
+        }
+    }
+}
@@ -0,0 +1,368 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Limit the rate at which pointer motion and scroll events reach a widget.
+
+use iced_core::event::{self, Event};
+use iced_core::mouse::{self, ScrollDelta};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, overlay, renderer, touch, window, Clipboard, Layout, Length, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Forwards at most one pointer motion or scroll event to its content per `interval`.
+pub fn throttle<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    interval: Duration,
+) -> Throttle<'a, Message> {
+    Throttle::new(content, interval)
+}
+
+/// Forwards at most one pointer motion or scroll event to its content per interval.
+///
+/// Events arriving between intervals are coalesced: only the latest pointer motion is kept, and
+/// scroll deltas are summed separately, so neither replaces the other. The pending events are
+/// delivered once the interval elapses, and always before a button release, so the content
+/// never misses the final position of a drag or any distance scrolled.
+#[must_use]
+pub struct Throttle<'a, Message> {
+    content: Element<'a, Message>,
+    interval: Duration,
+}
+
+impl<'a, Message> Throttle<'a, Message> {
+    pub fn new(content: impl Into<Element<'a, Message>>, interval: Duration) -> Self {
+        Self {
+            content: content.into(),
+            interval,
+        }
+    }
+
+    /// Delivers the pending events to the content, motion before scroll.
+    #[allow(clippy::too_many_arguments)]
+    fn flush(
+        &mut self,
+        tree: &mut Tree,
+        now: Instant,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let pending = state.take_pending();
+
+        if pending.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        state.last_forwarded = Some(now);
+
+        pending
+            .into_iter()
+            .fold(event::Status::Ignored, |status, event| {
+                status.merge(self.content.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    event,
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                ))
+            })
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for Throttle<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::WheelScrolled { .. }) => {
+                let now = Instant::now();
+                let state = tree.state.downcast_mut::<State>();
+
+                if let Some(last) = state.last_forwarded {
+                    let next = last + self.interval;
+
+                    if now < next {
+                        state.coalesce(event);
+                        shell.request_redraw(window::RedrawRequest::At(next));
+                        return event::Status::Ignored;
+                    }
+                }
+
+                // Deliver the event together with anything still held back.
+                state.coalesce(event);
+                return self.flush(
+                    tree, now, layout, cursor, renderer, clipboard, shell, viewport,
+                );
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(_))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                _ = self.flush(
+                    tree,
+                    Instant::now(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_ref::<State>();
+
+                if state
+                    .last_forwarded
+                    .is_some_and(|last| now >= last + self.interval)
+                {
+                    _ = self.flush(
+                        tree, now, layout, cursor, renderer, clipboard, shell, viewport,
+                    );
+                }
+            }
+
+            _ => (),
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<Throttle<'a, Message>> for Element<'a, Message> {
+    fn from(throttle: Throttle<'a, Message>) -> Self {
+        Self::new(throttle)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    last_forwarded: Option<Instant>,
+    /// The latest pointer motion held back by the throttle.
+    pending_motion: Option<Event>,
+    /// The scroll held back by the throttle, with one sum per kind of delta.
+    pending_scroll: Vec<ScrollDelta>,
+}
+
+impl State {
+    /// Merges an event held back by the throttle into the pending events.
+    fn coalesce(&mut self, event: Event) {
+        let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event else {
+            self.pending_motion = Some(event);
+            return;
+        };
+
+        for pending in &mut self.pending_scroll {
+            match (pending, delta) {
+                (ScrollDelta::Lines { x, y }, ScrollDelta::Lines { x: dx, y: dy })
+                | (ScrollDelta::Pixels { x, y }, ScrollDelta::Pixels { x: dx, y: dy }) => {
+                    *x += dx;
+                    *y += dy;
+                    return;
+                }
+                _ => (),
+            }
+        }
+
+        self.pending_scroll.push(delta);
+    }
+
+    /// Takes the pending events in the order they are delivered.
+    fn take_pending(&mut self) -> Vec<Event> {
+        self.pending_motion
+            .take()
+            .into_iter()
+            .chain(
+                self.pending_scroll
+                    .drain(..)
+                    .map(|delta| Event::Mouse(mouse::Event::WheelScrolled { delta })),
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::mouse::{self, ScrollDelta};
+    use iced_core::{Event, Point};
+
+    use super::State;
+
+    fn motion(x: f32, y: f32) -> Event {
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(x, y),
+        })
+    }
+
+    fn scroll(delta: ScrollDelta) -> Event {
+        Event::Mouse(mouse::Event::WheelScrolled { delta })
+    }
+
+    #[test]
+    fn test_coalesce_keeps_latest_motion_and_sums_scroll() {
+        let mut state = State::default();
+
+        state.coalesce(motion(1.0, 1.0));
+        state.coalesce(scroll(ScrollDelta::Lines { x: 0.0, y: 1.0 }));
+        state.coalesce(motion(2.0, 3.0));
+        state.coalesce(scroll(ScrollDelta::Lines { x: 0.5, y: 2.0 }));
+
+        assert_eq!(
+            state.take_pending(),
+            vec![
+                motion(2.0, 3.0),
+                scroll(ScrollDelta::Lines { x: 0.5, y: 3.0 })
+            ]
+        );
+        assert!(state.take_pending().is_empty());
+    }
+
+    #[test]
+    fn test_coalesce_keeps_scroll_of_each_kind() {
+        let mut state = State::default();
+
+        state.coalesce(scroll(ScrollDelta::Lines { x: 0.0, y: 1.0 }));
+        state.coalesce(scroll(ScrollDelta::Pixels { x: 0.0, y: 10.0 }));
+        state.coalesce(scroll(ScrollDelta::Lines { x: 0.0, y: 1.0 }));
+
+        assert_eq!(
+            state.take_pending(),
+            vec![
+                scroll(ScrollDelta::Lines { x: 0.0, y: 2.0 }),
+                scroll(ScrollDelta::Pixels { x: 0.0, y: 10.0 })
+            ]
+        );
+    }
+}
@@ -0,0 +1,642 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Show short-lived notifications, such as "Copied", over the application.
+
+use std::borrow::Cow;
+
+use iced_core::event::{self, Event};
+use iced_core::renderer::Quad;
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Id, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, window, Background, Clipboard, Color, Layout, Length, Point,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
+};
+use iced_style::container::StyleSheet;
+
+use crate::widget::popover::{place_popover, Side};
+use crate::{Element, Renderer};
+
+/// Space between the toasts and the bottom of the window.
+const MARGIN: f32 = 16.0;
+
+/// Time taken by a toast to fade out before it expires.
+const FADE_DURATION: Duration = Duration::from_millis(200);
+
+/// Identifies a toast within [`Toasts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+/// The toasts currently shown by a [`Toaster`].
+///
+/// Owned by the application, which adds a toast with [`Toasts::push`] or [`Toasts::push_near`],
+/// and removes it again with [`Toasts::remove`] when the toaster reports that it has expired.
+#[derive(Debug)]
+pub struct Toasts {
+    toasts: Vec<Toast>,
+    next_id: u64,
+    /// How long each toast is shown for.
+    pub timeout: Duration,
+    /// Hides toasts at once when they expire, rather than fading them out.
+    pub reduced_motion: bool,
+}
+
+#[derive(Debug)]
+struct Toast {
+    id: ToastId,
+    message: Cow<'static, str>,
+    /// The widget the toast is shown near, if any.
+    anchor: Option<Id>,
+    expires: Instant,
+}
+
+impl Toast {
+    /// The opacity of the toast at `now`, fading out during the `fade` before it expires.
+    fn opacity(&self, now: Instant, fade: Duration) -> f32 {
+        let remaining = self.expires.saturating_duration_since(now);
+
+        if remaining.is_zero() {
+            0.0
+        } else if remaining >= fade {
+            1.0
+        } else {
+            remaining.as_secs_f32() / fade.as_secs_f32()
+        }
+    }
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5))
+    }
+}
+
+impl Toasts {
+    #[must_use]
+    pub const fn new(timeout: Duration) -> Self {
+        Self {
+            toasts: Vec::new(),
+            next_id: 0,
+            timeout,
+            reduced_motion: false,
+        }
+    }
+
+    /// Shows a toast with the given message at the bottom of the window, below any already shown.
+    pub fn push(&mut self, message: impl Into<Cow<'static, str>>) -> ToastId {
+        self.insert(message.into(), None, Instant::now())
+    }
+
+    /// Shows a toast with the given message below the widget with the given id, such as the
+    /// [`container`](super::container) of a button which copied something.
+    ///
+    /// Toasts near the same widget are stacked. The toast is shown at the bottom of the window
+    /// instead if no container in the content of the [`Toaster`] has the id.
+    pub fn push_near(&mut self, message: impl Into<Cow<'static, str>>, anchor: Id) -> ToastId {
+        self.insert(message.into(), Some(anchor), Instant::now())
+    }
+
+    fn insert(&mut self, message: Cow<'static, str>, anchor: Option<Id>, now: Instant) -> ToastId {
+        let id = ToastId(self.next_id);
+        self.next_id += 1;
+
+        self.toasts.push(Toast {
+            id,
+            message,
+            anchor,
+            expires: now + self.timeout,
+        });
+
+        id
+    }
+
+    /// Removes a toast, if it is still shown.
+    pub fn remove(&mut self, id: ToastId) {
+        self.toasts.retain(|toast| toast.id != id);
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Time taken by a toast to fade out.
+    fn fade(&self) -> Duration {
+        if self.reduced_motion {
+            Duration::ZERO
+        } else {
+            FADE_DURATION
+        }
+    }
+}
+
+/// Shows `toasts` above `content`, near the widgets they were pushed for.
+pub fn toaster<'a, Message: 'static>(
+    toasts: &'a Toasts,
+    content: impl Into<Element<'a, Message>>,
+    on_expire: impl Fn(ToastId) -> Message + 'a,
+) -> Toaster<'a, Message> {
+    Toaster::new(toasts, content, on_expire)
+}
+
+/// Shows toasts above its content, near the widgets they were pushed for.
+///
+/// Toasts without such a widget are stacked at the bottom of the window. Each toast fades out
+/// just before its timeout, unless [`Toasts::reduced_motion`] is set, and `on_expire` is emitted
+/// with its id once the timeout has passed. The toasts are drawn in an overlay, and do not take
+/// input, so the content beneath remains usable.
+#[must_use]
+pub struct Toaster<'a, Message> {
+    content: Element<'a, Message>,
+    /// The message of each toast shown.
+    toasts: Vec<Element<'a, Message>>,
+    /// Each toast shown, in the same order as `toasts`.
+    shown: &'a [Toast],
+    fade: Duration,
+    spacing: f32,
+    on_expire: Box<dyn Fn(ToastId) -> Message + 'a>,
+}
+
+impl<'a, Message: 'static> Toaster<'a, Message> {
+    pub fn new(
+        toasts: &'a Toasts,
+        content: impl Into<Element<'a, Message>>,
+        on_expire: impl Fn(ToastId) -> Message + 'a,
+    ) -> Self {
+        let space_xxs = crate::theme::active().cosmic().space_xxs();
+
+        Self {
+            content: content.into(),
+            toasts: toasts
+                .toasts
+                .iter()
+                .map(|toast| {
+                    crate::widget::container(crate::widget::text(toast.message.as_ref()))
+                        .padding([space_xxs, space_xxs * 2])
+                        .into()
+                })
+                .collect(),
+            shown: &toasts.toasts,
+            fade: toasts.fade(),
+            spacing: f32::from(space_xxs),
+            on_expire: Box::new(on_expire),
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for Toaster<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.toasts)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let mut children: Vec<&mut Element<'a, Message>> = std::iter::once(&mut self.content)
+            .chain(&mut self.toasts)
+            .collect();
+
+        tree.diff_children(children.as_mut_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            state.now = Some(now);
+
+            for toast in self.shown {
+                if now >= toast.expires {
+                    // The application may take a while to remove the toast, so only emit once.
+                    if !state.expired.contains(&toast.id) {
+                        state.expired.push(toast.id);
+                        shell.publish((self.on_expire)(toast.id));
+                    }
+                } else {
+                    let fade_start = toast.expires.checked_sub(self.fade).unwrap_or(now);
+
+                    shell.request_redraw(if now >= fade_start {
+                        window::RedrawRequest::NextFrame
+                    } else {
+                        window::RedrawRequest::At(fade_start)
+                    });
+                }
+            }
+
+            state
+                .expired
+                .retain(|id| self.shown.iter().any(|toast| toast.id == *id));
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        let (content_tree, toasts_tree) = tree.children.split_at_mut(1);
+        let now = tree.state.downcast_ref::<State>().now;
+
+        let anchors = if self.shown.iter().any(|toast| toast.anchor.is_some()) {
+            let mut operation = FindBounds {
+                ids: self
+                    .shown
+                    .iter()
+                    .filter_map(|toast| toast.anchor.clone())
+                    .collect(),
+                found: Vec::new(),
+            };
+
+            self.content.as_widget().operate(
+                &mut content_tree[0],
+                layout,
+                renderer,
+                &mut operation,
+            );
+
+            self.shown
+                .iter()
+                .map(|toast| {
+                    let anchor = toast.anchor.as_ref()?;
+                    operation
+                        .found
+                        .iter()
+                        .find(|(id, _)| id == anchor)
+                        .map(|(_, bounds)| *bounds)
+                })
+                .collect()
+        } else {
+            vec![None; self.shown.len()]
+        };
+
+        let opacities = self
+            .shown
+            .iter()
+            .map(|toast| now.map_or(1.0, |now| toast.opacity(now, self.fade)))
+            .collect();
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .overlay(&mut content_tree[0], layout, renderer);
+
+        let toasts = (!self.shown.is_empty()).then(|| {
+            overlay::Element::new(
+                layout.position(),
+                Box::new(ToastOverlay {
+                    trees: toasts_tree,
+                    toasts: &self.toasts,
+                    anchors,
+                    opacities,
+                    spacing: self.spacing,
+                }),
+            )
+        });
+
+        match (content, toasts) {
+            (Some(content), Some(toasts)) => {
+                Some(overlay::Group::with_children(vec![content, toasts]).overlay())
+            }
+            (content, toasts) => content.or(toasts),
+        }
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<Toaster<'a, Message>> for Element<'a, Message> {
+    fn from(toaster: Toaster<'a, Message>) -> Self {
+        Self::new(toaster)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// Toasts whose expiry has been emitted, but which are still shown.
+    expired: Vec<ToastId>,
+    /// Time of the last frame drawn.
+    now: Option<Instant>,
+}
+
+/// Finds the bounds of the containers with the given ids.
+struct FindBounds {
+    ids: Vec<Id>,
+    found: Vec<(Id, Rectangle)>,
+}
+
+impl<T> Operation<T> for FindBounds {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if let Some(id) = id.filter(|id| self.ids.contains(id)) {
+            self.found.push((id.clone(), bounds));
+        }
+
+        operate_on_children(self);
+    }
+}
+
+struct ToastOverlay<'a, 'b, Message> {
+    trees: &'a mut [Tree],
+    toasts: &'a [Element<'b, Message>],
+    /// The bounds of the widget each toast is shown near, if it was found.
+    anchors: Vec<Option<Rectangle>>,
+    opacities: Vec<f32>,
+    spacing: f32,
+}
+
+impl<'a, 'b, Message> overlay::Overlay<Message, crate::Theme, Renderer>
+    for ToastOverlay<'a, 'b, Message>
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+        translation: Vector,
+    ) -> layout::Node {
+        let viewport = Rectangle::new(Point::ORIGIN, bounds);
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+        let spacing = self.spacing;
+
+        let mut nodes: Vec<layout::Node> = self
+            .toasts
+            .iter()
+            .zip(self.trees.iter_mut())
+            .map(|(toast, tree)| toast.as_widget().layout(tree, renderer, &limits))
+            .collect();
+
+        // Toasts near the same widget are placed against the area taken by those before them.
+        let mut stacks: Vec<(Rectangle, Rectangle)> = Vec::new();
+
+        for (node, anchor) in nodes.iter_mut().zip(&self.anchors) {
+            let Some(anchor) = anchor.map(|anchor| anchor + translation) else {
+                continue;
+            };
+
+            let size = node.size();
+            let index = stacks
+                .iter()
+                .position(|(shown, _)| *shown == anchor)
+                .unwrap_or_else(|| {
+                    stacks.push((anchor, anchor));
+                    stacks.len() - 1
+                });
+            let stack = &mut stacks[index].1;
+
+            let around = Rectangle {
+                x: stack.x - spacing,
+                y: stack.y - spacing,
+                width: stack.width + spacing * 2.0,
+                height: stack.height + spacing * 2.0,
+            };
+
+            let position = place_popover(around, size, viewport, Side::Bottom);
+            *stack = stack.union(&Rectangle::new(position, size));
+            *node = node
+                .clone()
+                .move_to(Point::new(position.x.round(), position.y.round()));
+        }
+
+        // The rest are stacked upward from the bottom of the window, the latest lowest.
+        let mut bottom = bounds.height - MARGIN;
+
+        for (node, _) in nodes
+            .iter_mut()
+            .zip(&self.anchors)
+            .rev()
+            .filter(|(_, anchor)| anchor.is_none())
+        {
+            let size = node.size();
+            bottom -= size.height;
+
+            *node = node.clone().move_to(Point::new(
+                ((bounds.width - size.width) / 2.0).max(0.0).round(),
+                bottom.max(0.0).round(),
+            ));
+
+            bottom -= spacing;
+        }
+
+        layout::Node::with_children(bounds, nodes)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        use iced_core::Renderer as _;
+
+        let appearance = theme.appearance(&crate::theme::Container::Tooltip);
+        let fade = |color: Color, opacity: f32| Color {
+            a: color.a * opacity,
+            ..color
+        };
+
+        for (((toast, tree), layout), &opacity) in self
+            .toasts
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+            .zip(&self.opacities)
+        {
+            if opacity <= 0.0 {
+                continue;
+            }
+
+            let bounds = layout.bounds();
+
+            if let Some(Background::Color(color)) = appearance.background {
+                renderer.fill_quad(
+                    Quad {
+                        bounds,
+                        border: appearance.border,
+                        shadow: Shadow::default(),
+                    },
+                    fade(color, opacity),
+                );
+            }
+
+            let style = renderer::Style {
+                icon_color: fade(style.icon_color, opacity),
+                text_color: fade(style.text_color, opacity),
+                scale_factor: style.scale_factor,
+            };
+
+            toast
+                .as_widget()
+                .draw(tree, renderer, theme, &style, layout, cursor, &bounds);
+        }
+    }
+
+    /// Toasts do not take input, so the cursor is never over them.
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::time::{Duration, Instant};
+
+    use super::{Toasts, FADE_DURATION};
+
+    #[test]
+    fn test_toast_shown_until_timeout() {
+        let now = Instant::now();
+        let mut toasts = Toasts::new(Duration::from_secs(5));
+
+        let id = toasts.insert("Copied".into(), None, now);
+        let toast = &toasts.toasts[0];
+        let fade = toasts.fade();
+
+        assert_eq!(toast.opacity(now, fade), 1.0);
+        assert_eq!(toast.opacity(now + Duration::from_millis(4800), fade), 1.0);
+        assert_eq!(toast.opacity(now + Duration::from_millis(4900), fade), 0.5);
+        assert_eq!(toast.opacity(now + Duration::from_secs(5), fade), 0.0);
+
+        toasts.remove(id);
+        assert!(toasts.is_empty());
+    }
+
+    #[test]
+    fn test_toast_reduced_motion() {
+        let now = Instant::now();
+        let mut toasts = Toasts::new(Duration::from_secs(5));
+        toasts.reduced_motion = true;
+
+        toasts.insert("Copied".into(), None, now);
+        let toast = &toasts.toasts[0];
+        let fade = toasts.fade();
+
+        let almost = now + Duration::from_secs(5) - FADE_DURATION / 2;
+        assert_eq!(toast.opacity(almost, fade), 1.0);
+        assert_eq!(toast.opacity(now + Duration::from_secs(5), fade), 0.0);
+    }
+
+    #[test]
+    fn test_toasts_stack() {
+        let now = Instant::now();
+        let mut toasts = Toasts::default();
+
+        let first = toasts.insert("Copied".into(), None, now);
+        let second = toasts.insert("Copied again".into(), None, now);
+        assert_ne!(first, second);
+
+        toasts.remove(first);
+        assert_eq!(toasts.toasts.len(), 1);
+        assert_eq!(toasts.toasts[0].id, second);
+    }
+}
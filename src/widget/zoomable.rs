@@ -0,0 +1,530 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Request a change of zoom level on Ctrl+scroll or pinch, and of rotation on a two-finger twist.
+
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::mouse::{self, ScrollDelta};
+use iced_core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use iced_core::{
+    keyboard, layout, overlay, renderer, touch, Clipboard, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+use crate::{Element, Renderer};
+
+/// Pixels of scrolling treated as one line, for touchpads which scroll in pixels.
+const PIXELS_PER_LINE: f32 = 20.0;
+
+/// Degrees a rotation snaps to while Shift is held.
+const ROTATION_SNAP: f32 = 15.0;
+
+/// Emits `on_zoom` with a new scale when its content is scrolled while Ctrl is held.
+pub fn zoomable<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    scale: f32,
+    on_zoom: impl Fn(f32) -> Message + 'a,
+) -> Zoomable<'a, Message> {
+    Zoomable::new(content, scale, on_zoom)
+}
+
+/// Emits a message with a new scale when its content is scrolled while Ctrl is held.
+///
+/// The scale is owned by the application, which applies it to the content, such as by
+/// changing its font size, and may persist it. Scrolling without Ctrl is passed to the content
+/// so that inner scrollables keep working.
+///
+/// Moving two fingers apart or together zooms in the same way. With
+/// [`on_rotate`](Self::on_rotate), twisting them also emits a new rotation in degrees, which
+/// snaps to multiples of 15° while Shift is held. A single finger is passed to the content, so
+/// that it may pan.
+#[derive(Setters)]
+#[must_use]
+pub struct Zoomable<'a, Message> {
+    #[setters(skip)]
+    content: Element<'a, Message>,
+    #[setters(skip)]
+    scale: f32,
+    #[setters(skip)]
+    on_zoom: Box<dyn Fn(f32) -> Message + 'a>,
+    #[setters(skip)]
+    rotation: f32,
+    #[setters(skip)]
+    on_rotate: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    /// The smallest scale which may be requested.
+    min_scale: f32,
+    /// The largest scale which may be requested.
+    max_scale: f32,
+    /// Factor the scale is multiplied by for each line scrolled.
+    step: f32,
+}
+
+impl<'a, Message> Zoomable<'a, Message> {
+    pub fn new(
+        content: impl Into<Element<'a, Message>>,
+        scale: f32,
+        on_zoom: impl Fn(f32) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            scale,
+            on_zoom: Box::new(on_zoom),
+            rotation: 0.0,
+            on_rotate: None,
+            min_scale: 0.5,
+            max_scale: 4.0,
+            step: 1.1,
+        }
+    }
+
+    /// Emits a new rotation, in degrees, when two fingers are twisted over the content.
+    pub fn on_rotate(mut self, rotation: f32, on_rotate: impl Fn(f32) -> Message + 'a) -> Self {
+        self.rotation = rotation;
+        self.on_rotate = Some(Box::new(on_rotate));
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message, crate::Theme, Renderer> for Zoomable<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                if let Some(scale) =
+                    state.zoom(delta, self.scale, self.step, self.min_scale, self.max_scale)
+                {
+                    #[allow(clippy::float_cmp)]
+                    if scale != self.scale {
+                        shell.publish((self.on_zoom)(scale));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if state.fingers.len() < 2 && layout.bounds().contains(position) =>
+            {
+                if state.press(id, position, self.scale, self.rotation) {
+                    let (first, a) = state.fingers[0];
+
+                    // The content saw the first finger pressed, so it is told the finger was
+                    // lost now that the gesture has taken it.
+                    if !state.claimed {
+                        state.claimed = true;
+                        let _ = self.content.as_widget_mut().on_event(
+                            &mut tree.children[0],
+                            Event::Touch(touch::Event::FingerLost {
+                                id: first,
+                                position: a,
+                            }),
+                            layout,
+                            cursor,
+                            renderer,
+                            clipboard,
+                            shell,
+                            viewport,
+                        );
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let finger = state.fingers.iter_mut().find(|(finger, _)| *finger == id);
+                let tracked = finger.is_some();
+                if let Some(finger) = finger {
+                    finger.1 = position;
+                }
+
+                if let (Some(pinch), [(_, a), (_, b)]) = (state.pinch, &state.fingers[..]) {
+                    if let Some(scale) = pinch.scale(*a, *b) {
+                        let scale = scale.clamp(self.min_scale, self.max_scale);
+
+                        #[allow(clippy::float_cmp)]
+                        if scale != self.scale {
+                            shell.publish((self.on_zoom)(scale));
+                        }
+                    }
+
+                    if let Some(on_rotate) = self.on_rotate.as_ref() {
+                        let rotation = pinch.rotation(*a, *b, state.modifiers.shift());
+
+                        #[allow(clippy::float_cmp)]
+                        if rotation != self.rotation {
+                            shell.publish(on_rotate(rotation));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                // A finger left over from a gesture stays with it until lifted.
+                if tracked && state.claimed {
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                let tracked = state.fingers.iter().any(|(finger, _)| *finger == id);
+                state.fingers.retain(|(finger, _)| *finger != id);
+                state.pinch = None;
+
+                let claimed = tracked && state.claimed;
+                if state.fingers.is_empty() {
+                    state.claimed = false;
+                }
+
+                if claimed {
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => (),
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn drag_destinations(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        self.content
+            .as_widget()
+            .drag_destinations(&tree.children[0], layout, dnd_rectangles);
+    }
+}
+
+impl<'a, Message: 'a> From<Zoomable<'a, Message>> for Element<'a, Message> {
+    fn from(zoomable: Zoomable<'a, Message>) -> Self {
+        Self::new(zoomable)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    modifiers: keyboard::Modifiers,
+    /// Fingers touching the content, and where they are.
+    fingers: Vec<(touch::Finger, Point)>,
+    pinch: Option<Pinch>,
+    /// Whether the fingers were taken from the content by a gesture.
+    claimed: bool,
+}
+
+impl State {
+    /// Records a finger pressed on the content.
+    ///
+    /// Returns whether it is the second finger, which starts a two-finger gesture from `scale`
+    /// and `rotation`. A single finger is left to the content.
+    fn press(&mut self, id: touch::Finger, position: Point, scale: f32, rotation: f32) -> bool {
+        self.fingers.push((id, position));
+
+        let [(_, a), (_, b)] = self.fingers[..] else {
+            return false;
+        };
+
+        self.pinch = Some(Pinch {
+            distance: a.distance(b),
+            angle: angle(a, b),
+            scale,
+            rotation,
+        });
+
+        true
+    }
+
+    /// The scale after scrolling by `delta` from `scale`, clamped to `min..=max`.
+    ///
+    /// Returns `None` if Ctrl is not held, when the scroll belongs to the content.
+    fn zoom(&self, delta: ScrollDelta, scale: f32, step: f32, min: f32, max: f32) -> Option<f32> {
+        if !self.modifiers.control() {
+            return None;
+        }
+
+        let lines = match delta {
+            ScrollDelta::Lines { y, .. } => y,
+            ScrollDelta::Pixels { y, .. } => y / PIXELS_PER_LINE,
+        };
+
+        Some((scale * step.powf(lines)).clamp(min, max))
+    }
+}
+
+/// The start of a two-finger gesture.
+#[derive(Clone, Copy)]
+struct Pinch {
+    distance: f32,
+    /// Angle of the line between the fingers, in degrees.
+    angle: f32,
+    scale: f32,
+    rotation: f32,
+}
+
+impl Pinch {
+    /// The scale with the fingers now at `a` and `b`, before clamping.
+    ///
+    /// Returns `None` if the gesture started with both fingers at the same point.
+    fn scale(&self, a: Point, b: Point) -> Option<f32> {
+        (self.distance > 0.0).then(|| self.scale * a.distance(b) / self.distance)
+    }
+
+    /// The rotation with the fingers now at `a` and `b`, snapped to [`ROTATION_SNAP`] if `snap`.
+    fn rotation(&self, a: Point, b: Point, snap: bool) -> f32 {
+        // Wrap the twist into ±180°, so that crossing the negative x axis does not turn a
+        // small twist into a full turn.
+        let twist = (angle(a, b) - self.angle + 180.0).rem_euclid(360.0) - 180.0;
+        let rotation = self.rotation + twist;
+
+        if snap {
+            (rotation / ROTATION_SNAP).round() * ROTATION_SNAP
+        } else {
+            rotation
+        }
+    }
+}
+
+/// Angle of the line from `a` to `b`, in degrees.
+fn angle(a: Point, b: Point) -> f32 {
+    (b.y - a.y).atan2(b.x - a.x).to_degrees()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+    use iced_core::keyboard::Modifiers;
+    use iced_core::mouse::ScrollDelta;
+    use iced_core::{touch::Finger, Point};
+
+    fn lines(y: f32) -> ScrollDelta {
+        ScrollDelta::Lines { x: 0.0, y }
+    }
+
+    #[test]
+    fn test_ctrl_scroll_zooms() {
+        let state = State {
+            modifiers: Modifiers::CTRL,
+            ..State::default()
+        };
+
+        let scale = state.zoom(lines(1.0), 1.0, 1.1, 0.5, 4.0).unwrap();
+        assert!((scale - 1.1).abs() < f32::EPSILON);
+
+        let scale = state.zoom(lines(-1.0), 1.0, 1.1, 0.5, 4.0).unwrap();
+        assert!(scale < 1.0);
+
+        // Touchpads scrolling in pixels zoom by the equivalent number of lines.
+        let pixels = ScrollDelta::Pixels { x: 0.0, y: 20.0 };
+        let scale = state.zoom(pixels, 1.0, 1.1, 0.5, 4.0).unwrap();
+        assert!((scale - 1.1).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_ctrl_scroll_clamps() {
+        let state = State {
+            modifiers: Modifiers::CTRL,
+            ..State::default()
+        };
+
+        assert_eq!(state.zoom(lines(100.0), 1.0, 1.1, 0.5, 4.0), Some(4.0));
+        assert_eq!(state.zoom(lines(-100.0), 1.0, 1.1, 0.5, 4.0), Some(0.5));
+    }
+
+    #[test]
+    fn test_plain_scroll_passes_through() {
+        let state = State::default();
+        assert_eq!(state.zoom(lines(1.0), 1.0, 1.1, 0.5, 4.0), None);
+
+        let state = State {
+            modifiers: Modifiers::SHIFT,
+            ..State::default()
+        };
+        assert_eq!(state.zoom(lines(1.0), 1.0, 1.1, 0.5, 4.0), None);
+    }
+
+    /// A point `radius` from the origin at `degrees`.
+    fn at(degrees: f32, radius: f32) -> Point {
+        let radians = degrees.to_radians();
+        Point::new(radius * radians.cos(), radius * radians.sin())
+    }
+
+    #[test]
+    fn test_single_finger_passes_through() {
+        let mut state = State::default();
+
+        assert!(!state.press(Finger(0), Point::new(10.0, 10.0), 1.0, 0.0));
+        assert!(state.pinch.is_none());
+    }
+
+    #[test]
+    fn test_two_finger_twist_rotates() {
+        let mut state = State::default();
+
+        assert!(!state.press(Finger(0), at(180.0, 50.0), 1.0, 30.0));
+        assert!(state.press(Finger(1), at(0.0, 50.0), 1.0, 30.0));
+
+        let pinch = state.pinch.unwrap();
+
+        // Twisting both fingers 20° around their midpoint turns the content with them.
+        let rotation = pinch.rotation(at(200.0, 50.0), at(20.0, 50.0), false);
+        assert!((rotation - 50.0).abs() < 0.001);
+
+        // Keeping their distance, the scale is unchanged.
+        let scale = pinch.scale(at(200.0, 50.0), at(20.0, 50.0)).unwrap();
+        assert!((scale - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_twist_across_negative_x_axis() {
+        let mut state = State::default();
+
+        state.press(Finger(0), at(10.0, 50.0), 1.0, 0.0);
+        state.press(Finger(1), at(190.0, 50.0), 1.0, 0.0);
+
+        // The angle from the first finger to the second wraps from -170° to 170°.
+        let rotation = state
+            .pinch
+            .unwrap()
+            .rotation(at(-10.0, 50.0), at(170.0, 50.0), false);
+        assert!((rotation + 20.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_shift_snaps_rotation() {
+        let mut state = State::default();
+
+        state.press(Finger(0), at(180.0, 50.0), 1.0, 0.0);
+        state.press(Finger(1), at(0.0, 50.0), 1.0, 0.0);
+        let pinch = state.pinch.unwrap();
+
+        let rotation = pinch.rotation(at(202.0, 50.0), at(22.0, 50.0), true);
+        assert!((rotation - 15.0).abs() < 0.001);
+
+        let rotation = pinch.rotation(at(187.0, 50.0), at(7.0, 50.0), true);
+        assert!(rotation.abs() < 0.001);
+    }
+}